@@ -0,0 +1,188 @@
+use std::{
+    collections::HashMap,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    process::exit,
+    time::Duration,
+};
+
+use utils::bench::{BenchConfig, BenchResult, Stats};
+
+use crate::{default_input_file, usage, BENCHMARKS};
+
+pub const USAGE: &str = "aoc bench <day|all> [--warmup N] [--iterations N] [--input FILE] [--save FILE] [--baseline FILE] [--threshold PERCENT]";
+
+struct Options {
+    days: Vec<usize>,
+    config: BenchConfig,
+    input_file: Option<String>,
+    save: Option<String>,
+    baseline: Option<String>,
+    threshold: f64,
+}
+
+fn parse_options(args: &[String]) -> Options {
+    let days = match args.first().map(|d| d.as_str()) {
+        Some("all") => (1..=25).collect(),
+        Some(day) => match day.parse() {
+            Ok(day @ 1..=25) => vec![day],
+            _ => usage(),
+        },
+        None => usage(),
+    };
+
+    let mut options = Options {
+        days,
+        config: BenchConfig::default(),
+        input_file: None,
+        save: None,
+        baseline: None,
+        threshold: 10.0,
+    };
+
+    let mut args = args.iter().skip(1);
+    while let Some(arg) = args.next() {
+        let value = args.next().unwrap_or_else(|| usage());
+        match arg.as_str() {
+            "--warmup" => options.config.warmup = value.parse().unwrap_or_else(|_| usage()),
+            "--iterations" => options.config.iterations = value.parse().unwrap_or_else(|_| usage()),
+            "--input" => options.input_file = Some(value.clone()),
+            "--save" => options.save = Some(value.clone()),
+            "--baseline" => options.baseline = Some(value.clone()),
+            "--threshold" => options.threshold = value.parse().unwrap_or_else(|_| usage()),
+            _ => usage(),
+        }
+    }
+
+    if options.input_file.is_some() && options.days.len() != 1 {
+        usage();
+    }
+
+    options
+}
+
+const STAGES: [&str; 3] = ["parse", "part_1", "part_2"];
+
+fn stages(result: &BenchResult) -> [Stats; 3] {
+    [result.parse, result.part_1, result.part_2]
+}
+
+// One line per day and stage: day, stage, min, median and p95 in nanoseconds, allocations.
+fn save(path: &str, results: &[(usize, BenchResult)]) {
+    let mut content = String::from("# day\tstage\tmin_ns\tmedian_ns\tp95_ns\tallocations\n");
+    for (day, result) in results {
+        for (stage, stats) in STAGES.iter().zip(stages(result)) {
+            content.push_str(&format!(
+                "{}\t{}\t{}\t{}\t{}\t{}\n",
+                day,
+                stage,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.p95.as_nanos(),
+                stats.allocations
+            ));
+        }
+    }
+    fs::write(path, content).unwrap_or_else(|e| panic!("Could not write {}: {}", path, e));
+}
+
+fn load(path: &str) -> HashMap<(usize, String), Stats> {
+    let content =
+        fs::read_to_string(path).unwrap_or_else(|e| panic!("Could not read {}: {}", path, e));
+
+    content
+        .lines()
+        .filter(|l| !l.starts_with('#') && !l.trim().is_empty())
+        .map(|l| {
+            let values: Vec<&str> = l.split('\t').collect();
+            let number = |i: usize| -> u64 {
+                values
+                    .get(i)
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or_else(|| panic!("Malformed baseline line \"{}\" in {}", l, path))
+            };
+            let stage = values.get(1).map(|s| s.to_string()).unwrap_or_default();
+            (
+                (number(0) as usize, stage),
+                Stats {
+                    min: Duration::from_nanos(number(2)),
+                    median: Duration::from_nanos(number(3)),
+                    p95: Duration::from_nanos(number(4)),
+                    allocations: number(5) as usize,
+                },
+            )
+        })
+        .collect()
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+}
+
+pub fn main(args: &[String]) {
+    let options = parse_options(args);
+    let baseline = options.baseline.as_deref().map(load);
+
+    let mut results = Vec::new();
+    let mut regressions = 0;
+
+    println!(
+        "{:<4} {:<7} {:>12} {:>12} {:>12} {:>12}",
+        "Day", "Stage", "Min", "Median", "P95", "Allocations"
+    );
+    for &day in options.days.iter() {
+        let input_file = options
+            .input_file
+            .clone()
+            .unwrap_or_else(|| default_input_file(day));
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            BENCHMARKS[day - 1](&input_file, &options.config)
+        }));
+        let Ok(result) = result else {
+            println!("{:0>2}   failed", day);
+            continue;
+        };
+
+        for (stage, stats) in STAGES.iter().zip(stages(&result)) {
+            let mut comparison = String::new();
+            let previous = baseline
+                .as_ref()
+                .and_then(|b| b.get(&(day, stage.to_string())));
+            if let Some(previous) = previous {
+                let change = (stats.median.as_secs_f64() / previous.median.as_secs_f64().max(1e-9)
+                    - 1.0)
+                    * 100.0;
+                comparison = format!(" {:+.1}%", change);
+                if change > options.threshold {
+                    regressions += 1;
+                    comparison.push_str(" REGRESSION");
+                }
+            }
+
+            println!(
+                "{:0>2}   {:<7} {:>12} {:>12} {:>12} {:>12}{}",
+                day,
+                stage,
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.p95),
+                stats.allocations,
+                comparison
+            );
+        }
+        results.push((day, result));
+    }
+
+    if let Some(path) = options.save.as_deref() {
+        save(path, &results);
+    }
+
+    if regressions > 0 {
+        eprintln!(
+            "{} stage(s) regressed by more than {}%",
+            regressions, options.threshold
+        );
+        exit(1);
+    }
+}
@@ -0,0 +1,134 @@
+use std::{env, path::Path, process::exit};
+
+use utils::{
+    answers::{get_answers_path, get_input_name, Answers},
+    bench::{bench, Benchmark, CountingAllocator},
+    solution::{solve, Solver},
+};
+
+mod benchmark;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const SOLVERS: [Solver; 25] = [
+    solve::<d01::Day01>,
+    solve::<d02::Day02>,
+    solve::<d03::Day03>,
+    solve::<d04::Day04>,
+    solve::<d05::Day05>,
+    solve::<d06::Day06>,
+    solve::<d07::Day07>,
+    solve::<d08::Day08>,
+    solve::<d09::Day09>,
+    solve::<d10::Day10>,
+    solve::<d11::Day11>,
+    solve::<d12::Day12>,
+    solve::<d13::Day13>,
+    solve::<d14::Day14>,
+    solve::<d15::Day15>,
+    solve::<d16::Day16>,
+    solve::<d17::Day17>,
+    solve::<d18::Day18>,
+    solve::<d19::Day19>,
+    solve::<d20::Day20>,
+    solve::<d21::Day21>,
+    solve::<d22::Day22>,
+    solve::<d23::Day23>,
+    solve::<d24::Day24>,
+    solve::<d25::Day25>,
+];
+
+const BENCHMARKS: [Benchmark; 25] = [
+    bench::<d01::Day01>,
+    bench::<d02::Day02>,
+    bench::<d03::Day03>,
+    bench::<d04::Day04>,
+    bench::<d05::Day05>,
+    bench::<d06::Day06>,
+    bench::<d07::Day07>,
+    bench::<d08::Day08>,
+    bench::<d09::Day09>,
+    bench::<d10::Day10>,
+    bench::<d11::Day11>,
+    bench::<d12::Day12>,
+    bench::<d13::Day13>,
+    bench::<d14::Day14>,
+    bench::<d15::Day15>,
+    bench::<d16::Day16>,
+    bench::<d17::Day17>,
+    bench::<d18::Day18>,
+    bench::<d19::Day19>,
+    bench::<d20::Day20>,
+    bench::<d21::Day21>,
+    bench::<d22::Day22>,
+    bench::<d23::Day23>,
+    bench::<d24::Day24>,
+    bench::<d25::Day25>,
+];
+
+fn usage() -> ! {
+    eprintln!("Usage: aoc <day> [1|2|all] [input_file]");
+    eprintln!("       {}", benchmark::USAGE);
+    exit(1);
+}
+
+fn default_input_file(day: usize) -> String {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("d{:0>2}", day))
+        .join("input")
+        .join("input.txt")
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if args.first().is_some_and(|a| a == "bench") {
+        benchmark::main(&args[1..]);
+        return;
+    }
+
+    let day: usize = match args.first().and_then(|d| d.parse().ok()) {
+        Some(day @ 1..=25) => day,
+        _ => usage(),
+    };
+    let part: Option<usize> = match args.get(1).map(|p| p.as_str()) {
+        None | Some("all") => None,
+        Some("1") => Some(1),
+        Some("2") => Some(2),
+        Some(_) => usage(),
+    };
+    let input_file = match args.get(2) {
+        Some(input_file) => input_file.clone(),
+        None => default_input_file(day),
+    };
+
+    let input_path = Path::new(&input_file);
+    let answers = Answers::load(get_answers_path(input_path).to_str().unwrap())
+        .unwrap_or_else(|e| panic!("{}", e));
+    let input_name = get_input_name(input_path);
+
+    let mut wrong_answer = false;
+    for answer in SOLVERS[day - 1](&input_file, part).answers {
+        let check = match answers.expected(&input_name, answer.part) {
+            None => String::new(),
+            Some(expected) if expected == answer.value => " (correct)".to_string(),
+            Some(expected) => {
+                wrong_answer = true;
+                format!(" (expected {})", expected)
+            }
+        };
+        println!(
+            "Day {} part {}: {}{}",
+            day, answer.part, answer.value, check
+        );
+    }
+
+    if wrong_answer {
+        exit(1);
+    }
+}
@@ -0,0 +1,112 @@
+use utils::{parse_file_into, solution::Solution};
+
+fn has_number(line: &str) -> Option<u32> {
+    const TO_FIND: [&str; 20] = [
+        "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "zero", "one", "two", "three", "four",
+        "five", "six", "seven", "eight", "nine",
+    ];
+    for (idx, &find) in TO_FIND.iter().enumerate() {
+        if line.starts_with(find) {
+            return Some(idx as u32 % 10);
+        }
+    }
+    None
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse_file_into::<String>(input_file)
+            .into_iter()
+            .map(|l| l.trim().to_string())
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn run(lines: &[String]) -> u32 {
+    // Preamble
+    let mut result = 0;
+
+    // Solve
+    for line in lines {
+        let first = line
+            .chars()
+            .find(|&x| x.is_ascii_digit())
+            .unwrap()
+            .to_digit(10)
+            .unwrap();
+
+        let last = line
+            .chars()
+            .rfind(|&x| x.is_ascii_digit())
+            .unwrap()
+            .to_digit(10)
+            .unwrap();
+
+        let numbers = first * 10 + last;
+        result += numbers
+    }
+
+    // Result
+    result
+}
+
+fn run2(lines: &[String]) -> u32 {
+    // Preamble
+    let mut result = 0;
+
+    // Solve
+    for line in lines {
+        let mut first = None;
+        for i in 0..line.len() {
+            if let Some(finding) = has_number(&line[i..]) {
+                first = Some(finding);
+                break;
+            }
+        }
+
+        let mut last = None;
+        for i in (0..line.len()).rev() {
+            if let Some(finding) = has_number(&line[i..]) {
+                last = Some(finding);
+                break;
+            }
+        }
+
+        let numbers = first.unwrap() * 10 + last.unwrap();
+        result += numbers
+    }
+
+    // Result
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::Day01;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day01>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day01>(file!(), "input_test_2", 2);
+    }
+}
@@ -0,0 +1,120 @@
+#![feature(iter_array_chunks)]
+use core::panic;
+
+use utils::{parse_file_into, solution::Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse_file_into::<String>(input_file)
+            .into_iter()
+            .map(|l| l.trim().to_string())
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn run(lines: &[String]) -> usize {
+    // Preamble
+    const MAX_RED: usize = 12;
+    const MAX_GREEN: usize = 13;
+    const MAX_BLUE: usize = 14;
+
+    let mut result = 0;
+
+    // Solve
+    'game: for line in lines {
+        let split = line.split([':', ' ', ',', ';']);
+        let view: Vec<&str> = split.filter(|i| !i.is_empty()).collect();
+
+        for [&num, &color] in view.iter().skip(2).array_chunks() {
+            let num_value: usize = num.parse().unwrap();
+            match color {
+                "red" => {
+                    if num_value > MAX_RED {
+                        continue 'game;
+                    }
+                }
+                "green" => {
+                    if num_value > MAX_GREEN {
+                        continue 'game;
+                    }
+                }
+                "blue" => {
+                    if num_value > MAX_BLUE {
+                        continue 'game;
+                    }
+                }
+                _ => panic!("Should not be here"),
+            }
+        }
+        let game_id: usize = view[1].parse().unwrap();
+        result += game_id;
+    }
+
+    // Result
+    result
+}
+
+fn run2(lines: &[String]) -> usize {
+    // Preamble
+    let mut result = 0;
+
+    // Solve
+    for line in lines {
+        let split = line.split([':', ' ', ',', ';']);
+        let view: Vec<&str> = split.filter(|i| !i.is_empty()).collect();
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+
+        for [&num, &color] in view.iter().skip(2).array_chunks() {
+            let num_value: usize = num.parse().unwrap();
+            match color {
+                "red" => {
+                    red = red.max(num_value);
+                }
+                "green" => {
+                    green = green.max(num_value);
+                }
+                "blue" => {
+                    blue = blue.max(num_value);
+                }
+                _ => panic!("Should not be here"),
+            }
+        }
+        result += red * green * blue;
+    }
+
+    // Result
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::Day02;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day02>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day02>(file!(), "input_test", 2);
+    }
+}
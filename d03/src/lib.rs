@@ -0,0 +1,207 @@
+use std::collections::HashSet;
+
+use utils::grid_point::GridPoint;
+use utils::{parse_file_into, solution::Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse_file_into::<String>(input_file)
+            .into_iter()
+            .map(|l| l.trim().to_string())
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+#[derive(Debug)]
+struct NumberRange {
+    pub x_from: usize,
+    pub x_to: usize,
+    pub y: usize,
+    pub number: usize,
+    pub flaged: bool,
+}
+
+impl NumberRange {
+    fn new(x_from: usize, y: usize) -> Self {
+        Self {
+            x_from,
+            x_to: x_from,
+            y,
+            number: 0,
+            flaged: false,
+        }
+    }
+}
+
+fn overlap(neigbor: &GridPoint, number: &NumberRange) -> bool {
+    neigbor.y == number.y && number.x_from <= neigbor.x && number.x_to >= neigbor.x
+}
+
+fn run(lines: &[String]) -> usize {
+    // Preamble
+    let mut symbols = Vec::new();
+    let mut numbers = Vec::new();
+
+    // Parse
+    let mut current_number: Option<NumberRange> = None;
+
+    for (y, line) in lines.iter().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if c.is_ascii_digit() {
+                let cn = current_number.get_or_insert(NumberRange::new(x, y));
+                cn.x_to = x;
+                continue;
+            } else if let Some(mut cn) = current_number {
+                cn.number = line[cn.x_from..cn.x_to + 1].parse().unwrap();
+                numbers.push(cn);
+                current_number = None;
+            }
+
+            if c == '.' {
+                continue;
+            }
+
+            symbols.push(GridPoint::new(x, y));
+        }
+
+        if let Some(mut cn) = current_number {
+            cn.number = line[cn.x_from..cn.x_to + 1].parse().unwrap();
+            numbers.push(cn);
+            current_number = None;
+        }
+    }
+
+    // Solve
+    for symbol in symbols {
+        let neigbors = symbol.generate_neigbors();
+        for neigbor in neigbors {
+            for number in numbers.iter_mut() {
+                if !number.flaged && overlap(&neigbor, number) {
+                    number.flaged = true;
+                }
+            }
+        }
+    }
+
+    // Result
+    let result: usize = numbers.iter().filter(|x| x.flaged).map(|y| y.number).sum();
+
+    result
+}
+
+#[derive(Debug)]
+struct Gears {
+    position: GridPoint,
+    numbers: HashSet<usize>,
+}
+
+impl Gears {
+    fn new(position: GridPoint) -> Self {
+        Self {
+            position,
+            numbers: HashSet::new(),
+        }
+    }
+}
+
+fn run2(lines: &[String]) -> usize {
+    // Preamble
+    let mut gears = Vec::new();
+    let mut numbers = Vec::new();
+
+    // Parse
+    let mut current_number: Option<NumberRange> = None;
+
+    for (y, line) in lines.iter().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            if c.is_ascii_digit() {
+                let cn = current_number.get_or_insert(NumberRange::new(x, y));
+                cn.x_to = x;
+                continue;
+            } else if let Some(mut cn) = current_number {
+                cn.number = line[cn.x_from..cn.x_to + 1].parse().unwrap();
+                numbers.push(cn);
+                current_number = None;
+            }
+
+            if c == '.' {
+                continue;
+            }
+            if c == '*' {
+                gears.push(Gears::new(GridPoint::new(x, y)));
+            }
+        }
+
+        if let Some(mut cn) = current_number {
+            cn.number = line[cn.x_from..cn.x_to + 1].parse().unwrap();
+            numbers.push(cn);
+            current_number = None;
+        }
+    }
+
+    // Solve
+    for gear in gears.iter_mut() {
+        let neigbors = gear.position.generate_neigbors();
+        for neigbor in neigbors {
+            for (i, number) in numbers.iter().enumerate() {
+                if overlap(&neigbor, number) {
+                    gear.numbers.insert(i);
+                }
+            }
+        }
+    }
+
+    // Result
+    let mut result = 0;
+
+    for gear in gears {
+        if gear.numbers.len() != 2 {
+            continue;
+        }
+
+        let mut gear_ratio = 0;
+
+        for number_idx in gear.numbers {
+            if gear_ratio == 0 {
+                gear_ratio += numbers[number_idx].number;
+            } else {
+                gear_ratio *= numbers[number_idx].number;
+            }
+        }
+
+        result += gear_ratio;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::Day03;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day03>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day03>(file!(), "input_test", 2);
+    }
+}
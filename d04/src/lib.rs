@@ -0,0 +1,128 @@
+use utils::{parse_file_into, solution::Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Card>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse_file_into(input_file)
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input.clone())
+    }
+}
+
+fn run(cards: &[Card]) -> usize {
+    // Solve
+    let result = cards.iter().map(|c| c.points).sum::<usize>();
+
+    // Result
+    result
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub struct Card {
+    id: usize,
+    winning_numbers: Vec<usize>,
+    your_numbers: Vec<usize>,
+    intersections: usize,
+    points: usize,
+    copies: usize,
+}
+
+impl std::fmt::Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Id: {}, Intersections: {}, Copies: {}",
+            self.id, self.intersections, self.copies
+        )
+    }
+}
+
+impl Card {
+    fn new(id: usize, winning_numbers: Vec<usize>, your_numbers: Vec<usize>) -> Self {
+        const VALUES: [usize; 11] = [0, 1, 2, 4, 8, 16, 32, 64, 128, 256, 512];
+        let intersections = your_numbers
+            .iter()
+            .filter(|n| winning_numbers.contains(n))
+            .count();
+        Self {
+            id,
+            winning_numbers,
+            your_numbers,
+            intersections,
+            points: VALUES[intersections],
+            copies: 1,
+        }
+    }
+}
+
+impl From<String> for Card {
+    fn from(value: String) -> Self {
+        let line = value.trim();
+        let mut split = line.split(':');
+        let game_part = split.next().unwrap();
+        let id = game_part[5..].trim_start().parse().unwrap();
+        let number_part = split.next().unwrap();
+        let mut number_split = number_part.split('|');
+        let left_numbers_part = number_split.next().unwrap();
+        let right_numbers_part = number_split.next().unwrap();
+
+        let left_numbers: Vec<usize> = left_numbers_part
+            .split(' ')
+            .filter(|s| !s.is_empty())
+            .map(|x| x.parse::<usize>().unwrap())
+            .collect();
+
+        let right_numbers: Vec<usize> = right_numbers_part
+            .split(' ')
+            .filter(|s| !s.is_empty())
+            .map(|x| x.parse::<usize>().unwrap())
+            .collect();
+
+        Card::new(id, left_numbers, right_numbers)
+    }
+}
+
+fn run2(mut cards: Vec<Card>) -> usize {
+    // Solve
+    for pos in 0..cards.len() {
+        let intersections = cards[pos].intersections;
+        let end = cards.len().min(pos + intersections + 1);
+        for to_add_idx in pos + 1..end {
+            cards[to_add_idx].copies += cards[pos].copies;
+        }
+    }
+
+    // Result
+    let result = cards.iter().map(|c| c.copies).sum::<usize>();
+    // cards.iter().for_each(|c| println!("{c}"));
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::Day04;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day04>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day04>(file!(), "input_test", 2);
+    }
+}
@@ -0,0 +1,171 @@
+use std::{collections::HashSet, str::FromStr};
+
+use utils::interval::{Interval, IntervalMap, IntervalSet};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AlmanacMap {
+    pub from: String,
+    pub to: String,
+    pub map: IntervalMap<usize>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    // Ordered so that every map's `to` is the next map's `from`.
+    pub maps: Vec<AlmanacMap>,
+}
+
+impl FromStr for Almanac {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut seeds = Vec::new();
+        let mut maps: Vec<AlmanacMap> = Vec::new();
+
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            let error = |reason: &str| format!("line {}: {} in \"{}\"", idx + 1, reason, line);
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(values) = line.strip_prefix("seeds:") {
+                seeds = values
+                    .split_whitespace()
+                    .map(|v| v.parse().map_err(|_| error("invalid seed")))
+                    .collect::<Result<_, _>>()?;
+            } else if let Some(header) = line.strip_suffix(" map:") {
+                let (from, to) = header
+                    .split_once("-to-")
+                    .ok_or_else(|| error("expected an X-to-Y header"))?;
+                maps.push(AlmanacMap {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    map: IntervalMap::new(),
+                });
+            } else {
+                let values: Vec<usize> = line
+                    .split_whitespace()
+                    .map(|v| v.parse().map_err(|_| error("invalid number")))
+                    .collect::<Result<_, _>>()?;
+                let [destination_start, source_start, length] = values[..] else {
+                    return Err(error("expected three numbers"));
+                };
+                maps.last_mut()
+                    .ok_or_else(|| error("range before the first map header"))?
+                    .map
+                    .insert(
+                        Interval::from_length(source_start, length),
+                        destination_start,
+                    );
+            }
+        }
+
+        Ok(Self {
+            seeds,
+            maps: order_chain(maps)?,
+        })
+    }
+}
+
+// Orders the maps from the only category nothing maps into to the last one.
+fn order_chain(mut maps: Vec<AlmanacMap>) -> Result<Vec<AlmanacMap>, String> {
+    if maps.is_empty() {
+        return Ok(maps);
+    }
+
+    let heads: Vec<usize> = (0..maps.len())
+        .filter(|i| !maps.iter().any(|other| other.to == maps[*i].from))
+        .collect();
+    let first = match heads[..] {
+        [first] => first,
+        [] => return Err("Every category is mapped into, the maps form a cycle".to_string()),
+        _ => {
+            let names: Vec<&str> = heads.iter().map(|i| maps[*i].from.as_str()).collect();
+            return Err(format!("Several first categories: {}", names.join(", ")));
+        }
+    };
+
+    let mut rtn = vec![maps.swap_remove(first)];
+    // Categories the chain has reached, a map back into one of them closes a loop.
+    let mut visited = HashSet::from([rtn[0].from.clone(), rtn[0].to.clone()]);
+    while !maps.is_empty() {
+        let current = &rtn.last().unwrap().to;
+        let next: Vec<usize> = (0..maps.len())
+            .filter(|i| &maps[*i].from == current)
+            .collect();
+        let next = match next[..] {
+            [next] => next,
+            [] => return Err(format!("No map continues from \"{}\"", current)),
+            _ => return Err(format!("Several maps continue from \"{}\"", current)),
+        };
+        if !visited.insert(maps[next].to.clone()) {
+            return Err(format!("The maps loop back to \"{}\"", maps[next].to));
+        }
+        rtn.push(maps.swap_remove(next));
+    }
+
+    Ok(rtn)
+}
+
+impl Almanac {
+    pub fn first_category(&self) -> Option<&str> {
+        self.maps.first().map(|m| m.from.as_str())
+    }
+
+    pub fn last_category(&self) -> Option<&str> {
+        self.maps.last().map(|m| m.to.as_str())
+    }
+
+    // A single map from category `from` to category `to`.
+    pub fn chain(&self, from: &str, to: &str) -> Option<IntervalMap<usize>> {
+        let start = self.maps.iter().position(|m| m.from == from)?;
+        let end = self.maps.iter().position(|m| m.to == to)?;
+        if end < start {
+            return None;
+        }
+
+        Some(
+            self.maps[start..=end]
+                .iter()
+                .fold(IntervalMap::new(), |composed, m| composed.compose(&m.map)),
+        )
+    }
+
+    // The whole chain, e.g. seed to location.
+    pub fn composed(&self) -> IntervalMap<usize> {
+        self.maps
+            .iter()
+            .fold(IntervalMap::new(), |composed, m| composed.compose(&m.map))
+    }
+
+    pub fn min_output(&self, inputs: &IntervalSet<usize>) -> Option<usize> {
+        self.composed().map_set(inputs).min()
+    }
+
+    // All first category values that end up in `outputs`, e.g. location to seed.
+    pub fn inputs_for(&self, outputs: &IntervalSet<usize>) -> IntervalSet<usize> {
+        self.composed().preimage(outputs)
+    }
+
+    pub fn seeds_as_values(&self) -> IntervalSet<usize> {
+        self.seeds
+            .iter()
+            .map(|seed| Interval::from_length(*seed, 1))
+            .collect()
+    }
+
+    // The seeds line read as pairs of start and length.
+    pub fn seeds_as_ranges(&self) -> Result<IntervalSet<usize>, String> {
+        let (seed_ranges, rest) = self.seeds.as_chunks::<2>();
+        if let [seed] = rest {
+            return Err(format!("Seed {} has no range length", seed));
+        }
+        Ok(seed_ranges
+            .iter()
+            .map(|[start, length]| Interval::from_length(*start, *length))
+            .collect())
+    }
+}
@@ -0,0 +1,149 @@
+use std::fs;
+
+use almanac::Almanac;
+use utils::solution::Solution;
+
+pub mod almanac;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        fs::read_to_string(input_file)
+            .unwrap()
+            .parse()
+            .unwrap_or_else(|e| panic!("Could not parse {}: {}", input_file, e))
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn run(almanac: &Almanac) -> usize {
+    // Solve
+    // Result
+    almanac.min_output(&almanac.seeds_as_values()).unwrap()
+}
+
+fn run2(almanac: &Almanac) -> usize {
+    // Solve
+    // Result
+    almanac
+        .min_output(&almanac.seeds_as_ranges().unwrap())
+        .unwrap()
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+    use utils::get_test_input_path;
+    use utils::interval::{Interval, IntervalSet};
+    use utils::solution::Solution;
+
+    use crate::almanac::Almanac;
+    use crate::Day05;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day05>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day05>(file!(), "input_test", 2);
+    }
+
+    #[test]
+    fn test_almanac_inversion() {
+        let input_path = get_test_input_path(file!());
+        let almanac = Day05::parse(input_path.to_str().unwrap());
+        assert_eq!(almanac.first_category(), Some("seed"));
+        assert_eq!(almanac.last_category(), Some("location"));
+
+        // Location 35 is reached from seed 13 only.
+        let location = IntervalSet::from_iter([Interval::from_length(35, 1)]);
+        let seeds = almanac.inputs_for(&location);
+        assert_eq!(
+            seeds.iter().copied().collect::<Vec<_>>(),
+            vec![Interval::new(13, 14)]
+        );
+
+        let soil_to_water = almanac.chain("soil", "water").unwrap();
+        assert_eq!(soil_to_water.get(81), 81);
+        assert_eq!(soil_to_water.get(53), 27);
+    }
+
+    #[test]
+    fn test_almanac_custom_stages() {
+        let almanac: Almanac = "seeds: 1 5\n\nb-to-c map:\n10 0 3\n\na-to-b map:\n0 5 2\n"
+            .parse()
+            .unwrap();
+        assert_eq!(almanac.first_category(), Some("a"));
+        // a: 5 -> b: 0 -> c: 10
+        assert_eq!(almanac.composed().get(5), 10);
+        assert_eq!(almanac.min_output(&almanac.seeds_as_values()), Some(10));
+    }
+
+    #[test]
+    fn test_almanac_errors() {
+        // The last seed has no range length.
+        let almanac: Almanac = "seeds: 1 5 7
+
+a-to-b map:
+0 5 2
+"
+        .parse()
+        .unwrap();
+        assert!(almanac.seeds_as_ranges().is_err());
+
+        // a -> b -> a has no first category.
+        let cyclic = "seeds: 1
+
+a-to-b map:
+0 5 2
+
+b-to-a map:
+0 5 2
+";
+        assert!(cyclic.parse::<Almanac>().is_err());
+
+        // a -> b -> c -> b loops after a valid first category.
+        let late_cycle =
+            "seeds: 1\n\na-to-b map:\n0 5 2\n\nb-to-c map:\n0 5 2\n\nc-to-b map:\n0 5 2\n";
+        assert!(late_cycle.parse::<Almanac>().is_err());
+
+        // a -> b and c -> b.
+        let two_heads = "seeds: 1
+
+a-to-b map:
+0 5 2
+
+c-to-b map:
+0 5 2
+";
+        assert!(two_heads.parse::<Almanac>().is_err());
+
+        // a -> b, then b -> c or b -> d.
+        let branching = "seeds: 1
+
+a-to-b map:
+0 5 2
+
+b-to-c map:
+0 5 2
+
+b-to-d map:
+0 5 2
+";
+        assert!(branching.parse::<Almanac>().is_err());
+    }
+}
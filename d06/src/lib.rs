@@ -0,0 +1,110 @@
+use utils::{parse_file_into, solution::Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse_file_into::<String>(input_file)
+            .into_iter()
+            .map(|l| l.trim().to_string())
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn calc_margin_with_quadratic_formular(time: f64, to_beat: f64) -> usize {
+    // Without two distinct roots the record can at best be tied.
+    let discriminant = time * time - 4.0 * to_beat;
+    if discriminant <= 0.0 {
+        return 0;
+    }
+    let sqr = f64::sqrt(discriminant);
+    let root1 = 0.5 * (time + sqr);
+    let root2 = 0.5 * (time - sqr);
+
+    // Only strictly longer distances win, so whole roots are ties and do not count.
+    ((f64::ceil(root1) - f64::floor(root2)) as usize).saturating_sub(1)
+}
+
+fn run(lines: &[String]) -> usize {
+    // Preamble
+    let mut result: usize = 0;
+
+    // Parse
+    let times: Vec<usize> = lines[0][10..]
+        .split_whitespace()
+        .filter(|x| !x.is_empty())
+        .map(|n| n.parse().unwrap())
+        .collect();
+    let to_beat: Vec<usize> = lines[1][10..]
+        .split_whitespace()
+        .filter(|x| !x.is_empty())
+        .map(|n| n.parse().unwrap())
+        .collect();
+
+    // Solve
+
+    for (i, time) in times.iter().enumerate() {
+        if i == 0 {
+            result = calc_margin_with_quadratic_formular(*time as f64, to_beat[i] as f64);
+        } else {
+            result *= calc_margin_with_quadratic_formular(*time as f64, to_beat[i] as f64);
+        }
+    }
+
+    // Result
+    result
+}
+
+fn run2(lines: &[String]) -> usize {
+    // Preamble
+    // Parse
+    let time_string = lines[0][10..].replace(' ', "");
+    let time: usize = time_string.parse().unwrap();
+    let to_beat_string: String = lines[1][10..].replace(' ', "");
+    let to_beat: usize = to_beat_string.parse().unwrap();
+
+    // Solve
+    let result = calc_margin_with_quadratic_formular(time as f64, to_beat as f64);
+
+    // Result
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::{calc_margin_with_quadratic_formular, Day06};
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day06>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day06>(file!(), "input_test", 2);
+    }
+
+    #[test]
+    fn test_unbeatable_records() {
+        // Holding for 2 of 4 ms only ties the record of 4 mm.
+        assert_eq!(calc_margin_with_quadratic_formular(4.0, 4.0), 0);
+        // 4 mm is the farthest a 4 ms race goes.
+        assert_eq!(calc_margin_with_quadratic_formular(4.0, 5.0), 0);
+        assert_eq!(calc_margin_with_quadratic_formular(4.0, 3.0), 1);
+        assert_eq!(calc_margin_with_quadratic_formular(7.0, 9.0), 4);
+    }
+}
@@ -0,0 +1,343 @@
+use std::cmp::Ordering;
+
+use utils::{parse_file_into, solution::Solution};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse_file_into(input_file)
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    fn new_from(hand_values: &[usize; 5]) -> Self {
+        let mut pairs: [usize; 15] = [0; 15];
+
+        for value in hand_values.iter() {
+            pairs[*value] += 1;
+        }
+
+        if pairs.contains(&5) {
+            return HandType::FiveOfAKind;
+        }
+
+        if pairs.contains(&4) {
+            return HandType::FourOfAKind;
+        }
+
+        if pairs.contains(&3) && pairs.contains(&2) {
+            return HandType::FullHouse;
+        }
+
+        if pairs.contains(&3) {
+            return HandType::ThreeOfAKind;
+        }
+
+        if pairs.iter().filter(|&v| v == &2).count() == 2 {
+            return HandType::TwoPair;
+        }
+
+        if pairs.contains(&2) {
+            return HandType::OnePair;
+        }
+
+        HandType::HighCard
+    }
+
+    fn new_from_with_jokers(hand_values: &[usize; 5]) -> HandType {
+        let mut pairs: [usize; 15] = [0; 15];
+
+        for value in hand_values.iter() {
+            pairs[*value] += 1;
+        }
+
+        let jokers = pairs[0];
+        pairs[0] = 0;
+
+        if pairs.contains(&5) {
+            return HandType::FiveOfAKind;
+        }
+
+        if pairs.contains(&4) {
+            if jokers == 1 {
+                return HandType::FiveOfAKind;
+            }
+            return HandType::FourOfAKind;
+        }
+
+        if pairs.contains(&3) && pairs.contains(&2) {
+            return HandType::FullHouse;
+        }
+
+        if pairs.contains(&3) {
+            match jokers {
+                0 => {
+                    return HandType::ThreeOfAKind;
+                }
+                1 => {
+                    return HandType::FourOfAKind;
+                }
+                2 => {
+                    return HandType::FiveOfAKind;
+                }
+                _ => panic!("Sould not be here."),
+            }
+        }
+
+        if pairs.iter().filter(|&v| v == &2).count() == 2 {
+            if jokers == 1 {
+                return HandType::FullHouse;
+            }
+            return HandType::TwoPair;
+        }
+
+        if pairs.contains(&2) {
+            match jokers {
+                0 => {
+                    return HandType::OnePair;
+                }
+                1 => {
+                    return HandType::ThreeOfAKind;
+                }
+                2 => {
+                    return HandType::FourOfAKind;
+                }
+                3 => {
+                    return HandType::FiveOfAKind;
+                }
+                _ => panic!("Sould not be here."),
+            }
+        }
+
+        match jokers {
+            0 => HandType::HighCard,
+            1 => HandType::OnePair,
+            2 => HandType::ThreeOfAKind,
+            3 => HandType::FourOfAKind,
+            4 => HandType::FiveOfAKind,
+            5 => HandType::FiveOfAKind,
+            _ => panic!("Sould not be here."),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Hand {
+    #[allow(dead_code)]
+    hand: String,
+    hand_values: [usize; 5],
+    bid: usize,
+    hand_type: HandType,
+}
+
+fn into_hand_values(c: char) -> usize {
+    match c {
+        '1' => 1,
+        '2' => 2,
+        '3' => 3,
+        '4' => 4,
+        '5' => 5,
+        '6' => 6,
+        '7' => 7,
+        '8' => 8,
+        '9' => 9,
+        'T' => 10,
+        'J' => 11,
+        'Q' => 12,
+        'K' => 13,
+        'A' => 14,
+        _ => panic!("Should not be here."),
+    }
+}
+
+impl From<String> for Hand {
+    fn from(value: String) -> Self {
+        let mut split = value.split(' ');
+        let hand: String = split.next().unwrap().into();
+        let bid = split.next().unwrap().parse().unwrap();
+
+        let mut hand_values = [0; 5];
+
+        for (i, v) in hand.chars().map(into_hand_values).enumerate() {
+            hand_values[i] = v;
+        }
+
+        let hand_type = HandType::new_from(&hand_values);
+
+        Self {
+            hand,
+            hand_values,
+            bid,
+            hand_type,
+        }
+    }
+}
+
+fn sort_hands(left: &Hand, right: &Hand) -> Ordering {
+    match left.hand_type.cmp(&right.hand_type) {
+        Ordering::Equal => {}
+        ord => {
+            return ord;
+        }
+    }
+
+    for i in 0..4 {
+        match left.hand_values[i].cmp(&right.hand_values[i]) {
+            Ordering::Equal => {}
+            ord => {
+                return ord;
+            }
+        }
+    }
+
+    left.hand_values[4].cmp(&right.hand_values[4])
+}
+
+#[derive(Debug)]
+struct JokerHand {
+    #[allow(dead_code)]
+    hand: String,
+    hand_values: [usize; 5],
+    bid: usize,
+    hand_type: HandType,
+}
+
+fn into_hand_values_joker(c: char) -> usize {
+    match c {
+        '1' => 1,
+        '2' => 2,
+        '3' => 3,
+        '4' => 4,
+        '5' => 5,
+        '6' => 6,
+        '7' => 7,
+        '8' => 8,
+        '9' => 9,
+        'T' => 10,
+        'J' => 0,
+        'Q' => 12,
+        'K' => 13,
+        'A' => 14,
+        _ => panic!("Should not be here."),
+    }
+}
+
+impl From<String> for JokerHand {
+    fn from(value: String) -> Self {
+        let mut split = value.split(' ');
+        let hand: String = split.next().unwrap().into();
+        let bid = split.next().unwrap().parse().unwrap();
+
+        let mut hand_values = [0; 5];
+
+        for (i, v) in hand.chars().map(into_hand_values_joker).enumerate() {
+            hand_values[i] = v;
+        }
+
+        let hand_type = HandType::new_from_with_jokers(&hand_values);
+
+        Self {
+            hand,
+            hand_values,
+            bid,
+            hand_type,
+        }
+    }
+}
+
+fn sort_hands_joker(left: &JokerHand, right: &JokerHand) -> Ordering {
+    match left.hand_type.cmp(&right.hand_type) {
+        Ordering::Equal => {}
+        ord => {
+            return ord;
+        }
+    }
+
+    for i in 0..4 {
+        match left.hand_values[i].cmp(&right.hand_values[i]) {
+            Ordering::Equal => {}
+            ord => {
+                return ord;
+            }
+        }
+    }
+
+    left.hand_values[4].cmp(&right.hand_values[4])
+}
+
+fn run(lines: &[String]) -> usize {
+    // Preamble
+    // Parse
+    let mut values: Vec<Hand> = lines.iter().cloned().map(Hand::from).collect();
+
+    values.sort_by(sort_hands);
+
+    // Solve
+    let mut result = 0;
+
+    for (idx, hand) in values.iter().enumerate() {
+        result += (idx + 1) * hand.bid;
+    }
+
+    // Result
+    result
+}
+
+fn run2(lines: &[String]) -> usize {
+    // Preamble
+    // Parse
+    let mut values: Vec<JokerHand> = lines.iter().cloned().map(JokerHand::from).collect();
+
+    values.sort_by(sort_hands_joker);
+
+    // Solve
+    let mut result = 0;
+
+    for (idx, hand) in values.iter().enumerate() {
+        result += (idx + 1) * hand.bid;
+    }
+
+    // Result
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::Day07;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day07>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day07>(file!(), "input_test", 2);
+    }
+}
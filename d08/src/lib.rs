@@ -0,0 +1,377 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use utils::{
+    graph::{EdgeKind, Graph},
+    number::{checked_lcm, crt},
+    solution::Solution,
+};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Network;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse(input_file)
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+#[derive(Debug)]
+pub enum LeftRight {
+    Left,
+    Right,
+}
+
+impl From<char> for LeftRight {
+    fn from(value: char) -> Self {
+        match value {
+            'L' => LeftRight::Left,
+            'R' => LeftRight::Right,
+            _ => panic!("Should not be here"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Network {
+    left_rights: Vec<LeftRight>,
+    ways: HashMap<String, (String, String)>,
+}
+
+fn parse(input_file: &str) -> Network {
+    // Preamble
+    let mut ways: HashMap<String, (String, String)> = HashMap::new();
+
+    // Parse
+    let file = File::open(input_file).unwrap();
+    let reader = BufReader::new(file);
+    let mut lines = reader.lines();
+
+    let left_rights: Vec<LeftRight> = lines
+        .next()
+        .unwrap()
+        .unwrap()
+        .chars()
+        .map(|c| c.into())
+        .collect();
+    for line in lines.skip(1) {
+        let line = line.unwrap().trim().to_string();
+        let node = line[0..3].to_string();
+        let left = line[7..10].to_string();
+        let right = line[12..15].to_string();
+
+        ways.insert(node, (left, right));
+    }
+
+    Network { left_rights, ways }
+}
+
+fn run(network: &Network) -> usize {
+    // Preamble
+    const START: &str = "AAA";
+    const GOAL: &str = "ZZZ";
+    let ways = &network.ways;
+
+    // Solve
+    let mut current_node = START;
+    let lr_pos = network.left_rights.iter().cycle();
+    let mut result: usize = 0;
+
+    for lr in lr_pos {
+        if current_node == GOAL {
+            break;
+        }
+        result += 1;
+
+        let possible_ways = ways.get(current_node).expect("This is not the way!");
+
+        current_node = match lr {
+            LeftRight::Left => &possible_ways.0,
+            LeftRight::Right => &possible_ways.1,
+        }
+    }
+
+    // Result
+    result
+}
+
+// Nodes as indices, with the instruction list to follow.
+struct Maps<'a> {
+    left_rights: &'a [LeftRight],
+    // An edge to the left and one to the right successor of every node.
+    nodes: Graph<LeftRight>,
+    left: Vec<usize>,
+    right: Vec<usize>,
+    goal: Vec<bool>,
+}
+
+impl<'a> Maps<'a> {
+    fn new(network: &'a Network) -> Self {
+        let mut nodes = Graph::new(EdgeKind::Directed);
+        for (node, (left, right)) in network.ways.iter() {
+            nodes.add_edge(node, left, LeftRight::Left);
+            nodes.add_edge(node, right, LeftRight::Right);
+        }
+
+        let next = |id: usize, way: fn(&LeftRight) -> bool| {
+            nodes
+                .neighbours(id)
+                .find(|(_, w)| way(w))
+                .map(|(next, _)| next)
+                .expect("This is not the way!")
+        };
+        let left = (0..nodes.len())
+            .map(|id| next(id, |w| matches!(w, LeftRight::Left)))
+            .collect();
+        let right = (0..nodes.len())
+            .map(|id| next(id, |w| matches!(w, LeftRight::Right)))
+            .collect();
+        let goal = nodes
+            .names()
+            .iter()
+            .map(|n| n.as_bytes()[2] == b'Z')
+            .collect();
+
+        Self {
+            left_rights: &network.left_rights,
+            nodes,
+            left,
+            right,
+            goal,
+        }
+    }
+
+    fn next(&self, node: usize, step: usize) -> usize {
+        match self.left_rights[step % self.left_rights.len()] {
+            LeftRight::Left => self.left[node],
+            LeftRight::Right => self.right[node],
+        }
+    }
+}
+
+// After `prefix` steps a ghost's (node, instruction) state repeats every `period` steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Ghost {
+    prefix: usize,
+    period: usize,
+    // Steps below `prefix + period` that end on a goal node.
+    hits: Vec<usize>,
+}
+
+impl Ghost {
+    fn new(maps: &Maps, start: usize) -> Self {
+        let instructions = maps.left_rights.len();
+        let mut seen = vec![usize::MAX; maps.goal.len() * instructions];
+        let mut hits = Vec::new();
+        let mut node = start;
+        let mut step = 0;
+
+        loop {
+            let state = node * instructions + step % instructions;
+            if seen[state] != usize::MAX {
+                return Self {
+                    prefix: seen[state],
+                    period: step - seen[state],
+                    hits,
+                };
+            }
+            seen[state] = step;
+            if maps.goal[node] {
+                hits.push(step);
+            }
+            node = maps.next(node, step);
+            step += 1;
+        }
+    }
+
+    fn on_goal(&self, step: usize) -> bool {
+        let step = if step < self.prefix {
+            step
+        } else {
+            self.prefix + (step - self.prefix) % self.period
+        };
+        self.hits.binary_search(&step).is_ok()
+    }
+}
+
+// First step on which every ghost stands on a goal node.
+fn first_common_step(ghosts: &[Ghost]) -> Option<usize> {
+    let start = ghosts.iter().map(|g| g.prefix).max()?;
+    if let Some(step) = (0..start).find(|s| ghosts.iter().all(|g| g.on_goal(*s))) {
+        return Some(step);
+    }
+
+    // From `start` on everything is periodic. When there are fewer steps in a combined period
+    // than combinations of hits, walking that period is cheaper than CRT.
+    let combined = ghosts
+        .iter()
+        .try_fold(1usize, |acc, g| checked_lcm(acc, g.period));
+    let cyclic_hits: Vec<Vec<(i128, i128)>> = ghosts
+        .iter()
+        .map(|g| {
+            g.hits
+                .iter()
+                .filter(|h| **h >= g.prefix)
+                .map(|h| (*h as i128, g.period as i128))
+                .collect()
+        })
+        .collect();
+    let combinations = cyclic_hits
+        .iter()
+        .fold(1usize, |acc, h| acc.saturating_mul(h.len()));
+
+    if let Some(combined) = combined.filter(|c| *c <= combinations) {
+        return (start..start + combined).find(|s| ghosts.iter().all(|g| g.on_goal(*s)));
+    }
+
+    let mut congruences: Vec<Vec<(i128, i128)>> = vec![Vec::new()];
+    for hits in cyclic_hits.iter() {
+        congruences = congruences
+            .iter()
+            .flat_map(|c| {
+                hits.iter().map(move |h| {
+                    let mut c = c.clone();
+                    c.push(*h);
+                    c
+                })
+            })
+            .collect();
+    }
+
+    congruences
+        .iter()
+        .filter_map(|c| crt(c))
+        .map(|(residue, modulus)| {
+            let behind = (start as i128 - residue).max(0);
+            residue + (behind + modulus - 1) / modulus * modulus
+        })
+        .min()
+        .map(|step| step as usize)
+}
+
+fn run2(network: &Network) -> usize {
+    // Preamble
+    let maps = Maps::new(network);
+
+    // Solve
+    let ghosts: Vec<Ghost> = maps
+        .nodes
+        .names()
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| name.as_bytes()[2] == b'A')
+        .map(|(node, _)| Ghost::new(&maps, node))
+        .collect();
+
+    // Result
+    first_common_step(&ghosts).expect("The ghosts never all reach a Z node together")
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+    use utils::write_temp_input;
+
+    use crate::Day08;
+    use crate::{first_common_step, parse, run2, Ghost, Maps};
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day08>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day08>(file!(), "input_test_2", 2);
+    }
+
+    #[test]
+    fn test_offsets_and_multiple_hits() {
+        // 1AA hits on every even step from 2, 2AA on steps 1, 4, 7, ... and 3AA on every step
+        // from 1 that is not a multiple of 3. Only step 4 works for all of them, the lcm of the
+        // first hits would be 2.
+        let input_file = write_temp_input(
+            "d08_offsets.txt",
+            "L\n\n\
+            1AA = (1BB, 1BB)\n1BB = (1ZZ, 1ZZ)\n1ZZ = (1BB, 1BB)\n\
+            2AA = (2ZZ, 2ZZ)\n2ZZ = (2CC, 2CC)\n2CC = (2DD, 2DD)\n2DD = (2ZZ, 2ZZ)\n\
+            3AA = (3ZZ, 3ZZ)\n3ZZ = (3YZ, 3YZ)\n3YZ = (3CC, 3CC)\n3CC = (3ZZ, 3ZZ)\n",
+        );
+        let network = parse(&input_file);
+        assert_eq!(run2(&network), 4);
+
+        let maps = Maps::new(&network);
+        let ghost = |name: &str| Ghost::new(&maps, maps.nodes.id(name).unwrap());
+        assert_eq!(
+            ghost("3AA"),
+            Ghost {
+                prefix: 1,
+                period: 3,
+                hits: vec![1, 2]
+            }
+        );
+        assert_eq!(first_common_step(&[ghost("1AA"), ghost("3AA")]), Some(2));
+    }
+
+    #[test]
+    fn test_large_periods() {
+        // Coprime periods with one hit each take the CRT path.
+        let ghosts = [
+            Ghost {
+                prefix: 3,
+                period: 1_000_003,
+                hits: vec![10],
+            },
+            Ghost {
+                prefix: 0,
+                period: 999_983,
+                hits: vec![7],
+            },
+        ];
+        let step = first_common_step(&ghosts).unwrap();
+        assert_eq!(step % 1_000_003, 10);
+        assert_eq!(step % 999_983, 7);
+        assert!(step < 1_000_003 * 999_983);
+
+        let never = [
+            Ghost {
+                prefix: 1,
+                period: 2,
+                hits: vec![1],
+            },
+            Ghost {
+                prefix: 1,
+                period: 2,
+                hits: vec![2],
+            },
+        ];
+        assert_eq!(first_common_step(&never), None);
+
+        // Many hits in short cycles are simply walked.
+        let busy = [
+            Ghost {
+                prefix: 0,
+                period: 4,
+                hits: vec![0, 1, 2, 3],
+            },
+            Ghost {
+                prefix: 0,
+                period: 4,
+                hits: vec![3],
+            },
+        ];
+        assert_eq!(first_common_step(&busy), Some(3));
+    }
+}
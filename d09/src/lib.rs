@@ -0,0 +1,124 @@
+use utils::{parse_into_i64_vector_vector, solution::Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse_into_i64_vector_vector(input_file)
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn get_next_value(values: &[i64]) -> i64 {
+    let mut lines = Vec::new();
+
+    let mut current_line = values;
+
+    loop {
+        let mut is_finished = true;
+        let mut next_line = Vec::new();
+
+        for [v1, v2] in current_line.array_windows() {
+            let diff = v2 - v1;
+            if diff != 0 {
+                is_finished = false;
+            }
+
+            next_line.push(diff);
+        }
+
+        if is_finished {
+            break;
+        }
+        lines.push(next_line);
+        current_line = lines.last().unwrap();
+    }
+
+    let mut last_last_value = *lines.last().unwrap().first().unwrap();
+
+    for i in (0..lines.len() - 1).rev() {
+        let last_value = *lines[i].last().unwrap();
+        last_last_value += last_value;
+    }
+
+    values.last().unwrap() + last_last_value
+}
+
+fn get_previous_value(values: &[i64]) -> i64 {
+    let mut lines = Vec::new();
+
+    let mut current_line = values;
+
+    loop {
+        let mut is_finished = true;
+        let mut next_line = Vec::new();
+
+        for [v1, v2] in current_line.array_windows() {
+            let diff = v2 - v1;
+            if diff != 0 {
+                is_finished = false;
+            }
+
+            next_line.push(diff);
+        }
+
+        if is_finished {
+            break;
+        }
+        lines.push(next_line);
+        current_line = lines.last().unwrap();
+    }
+
+    let mut last_first_value = *lines.last().unwrap().first().unwrap();
+
+    for i in (0..lines.len() - 1).rev() {
+        let first_value = *lines[i].first().unwrap();
+        last_first_value = first_value - last_first_value;
+    }
+
+    values.first().unwrap() - last_first_value
+}
+
+fn run(input: &[Vec<i64>]) -> i64 {
+    // Solve
+    let result: i64 = input.iter().map(|i| get_next_value(i)).sum();
+
+    // Result
+    result
+}
+
+fn run2(input: &[Vec<i64>]) -> i64 {
+    // Solve
+    let result: i64 = input.iter().map(|i| get_previous_value(i)).sum();
+
+    // Result
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::Day09;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day09>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day09>(file!(), "input_test_2", 2);
+    }
+}
@@ -0,0 +1,235 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use utils::{
+    grid::Grid, grid_direction::GridDirection, grid_point::GridPoint, search::bfs_distances,
+    solution::Solution,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = (Grid<char>, GridPoint);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse(input_file)
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(&input.0, input.1)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input.0.clone(), input.1)
+    }
+}
+
+// Directions a pipe connects to.
+fn pipe_directions(pipe: char) -> Vec<GridDirection> {
+    match pipe {
+        '|' => vec![GridDirection::Up, GridDirection::Down],
+        '-' => vec![GridDirection::Right, GridDirection::Left],
+        'L' => vec![GridDirection::Up, GridDirection::Right],
+        'J' => vec![GridDirection::Up, GridDirection::Left],
+        '7' => vec![GridDirection::Down, GridDirection::Left],
+        'F' => vec![GridDirection::Right, GridDirection::Down],
+        '.' => Vec::new(),
+        _ => panic!("Should not be here."),
+    }
+}
+
+fn is_valid_move(next_value: Option<&char>, last_direction: GridDirection) -> bool {
+    match next_value {
+        Some(c) => match c {
+            '|' => last_direction == GridDirection::Up || last_direction == GridDirection::Down,
+            '-' => last_direction == GridDirection::Right || last_direction == GridDirection::Left,
+            'L' => last_direction == GridDirection::Down || last_direction == GridDirection::Left,
+            'J' => last_direction == GridDirection::Down || last_direction == GridDirection::Right,
+            '7' => last_direction == GridDirection::Up || last_direction == GridDirection::Right,
+            'F' => last_direction == GridDirection::Up || last_direction == GridDirection::Left,
+            '.' => false,
+            _ => panic!("Should not be here."),
+        },
+        None => false,
+    }
+}
+
+fn parse(input_file: &str) -> (Grid<char>, GridPoint) {
+    // Preamble
+    let mut data: Vec<Vec<char>> = Vec::new();
+    let mut start_position: GridPoint = GridPoint::default();
+    // Parse
+    let file = File::open(input_file).unwrap();
+    let reader = BufReader::new(file);
+
+    for (y, line) in reader.lines().enumerate() {
+        let mut row = Vec::new();
+        for (x, c) in line.unwrap().trim().chars().enumerate() {
+            if c == 'S' {
+                start_position.x = x;
+                start_position.y = y;
+            }
+            row.push(c);
+        }
+        data.push(row);
+    }
+
+    (data.try_into().unwrap(), start_position)
+}
+
+// Directions the start connects to, the neighbours in those directions have to connect back.
+fn start_directions(grid: &Grid<char>, start_position: GridPoint) -> Vec<GridDirection> {
+    GridDirection::get_directions()
+        .into_iter()
+        .filter(|direction| {
+            start_position
+                .next_by_direction_with_check(direction, grid.get_max_x(), grid.get_max_y())
+                .is_some_and(|next| is_valid_move(grid.get_from_point(&next), *direction))
+        })
+        .collect()
+}
+
+// Steps from the start to every tile of the loop.
+fn loop_distances(grid: &Grid<char>, start_position: GridPoint) -> HashMap<GridPoint, usize> {
+    let max_x = grid.get_max_x();
+    let max_y = grid.get_max_y();
+
+    bfs_distances(
+        [start_position],
+        |position: &GridPoint| -> Vec<GridPoint> {
+            let directions = if *position == start_position {
+                start_directions(grid, start_position)
+            } else {
+                pipe_directions(*grid.get_from_point(position).unwrap())
+            };
+            directions
+                .iter()
+                .filter_map(|direction| {
+                    position.next_by_direction_with_check(direction, max_x, max_y)
+                })
+                .collect()
+        },
+        None,
+    )
+}
+
+fn run(grid: &Grid<char>, start_position: GridPoint) -> usize {
+    // Solve
+    let distances = loop_distances(grid, start_position);
+
+    // Result
+    distances.into_values().max().unwrap_or(0)
+}
+
+fn run2(mut grid: Grid<char>, start_position: GridPoint) -> usize {
+    let max_x = grid.get_max_x();
+    let max_y = grid.get_max_y();
+
+    // Solve
+    let start_is = resolve_start(&start_directions(&grid, start_position));
+    let pipe_loop: HashSet<GridPoint> = loop_distances(&grid, start_position).into_keys().collect();
+
+    if let Some(start_char) = start_is {
+        grid.set_from_point(&start_position, start_char);
+    } else {
+        panic!("Could not determin start char.");
+    }
+
+    let mut inside_loop = false;
+    let mut last_direction = None;
+    let mut result: usize = 0;
+
+    for y in 0..max_y {
+        for x in 0..max_x {
+            let c = if pipe_loop.contains(&GridPoint::new(x, y)) {
+                *grid.get(x, y).unwrap()
+            } else {
+                '.'
+            };
+
+            match c {
+                '|' => inside_loop = !inside_loop,
+                '-' => {}
+                'L' => {
+                    if last_direction.is_some() {
+                        panic!("Should not happen");
+                    }
+                    last_direction = Some(GridDirection::Down);
+                }
+                'J' => {
+                    if let Some(last) = last_direction {
+                        if last == GridDirection::Up {
+                            inside_loop = !inside_loop;
+                        }
+
+                        last_direction = None;
+                    } else {
+                        panic!("should not happen");
+                    }
+                }
+                '7' => {
+                    if let Some(last) = last_direction {
+                        if last == GridDirection::Down {
+                            inside_loop = !inside_loop;
+                        }
+
+                        last_direction = None;
+                    } else {
+                        panic!("should not happen");
+                    }
+                }
+                'F' => {
+                    if last_direction.is_some() {
+                        panic!("Should not happen");
+                    }
+                    last_direction = Some(GridDirection::Up);
+                }
+                '.' => {
+                    if inside_loop {
+                        result += 1;
+                        grid.set(x, y, 'I');
+                    }
+                }
+                'S' => {
+                    panic!("should not happen");
+                }
+
+                _ => panic!("Should not be here!"),
+            }
+        }
+    }
+
+    // grid.print_data();
+
+    // Result
+    result
+}
+
+// The directions come in the order of `GridDirection::get_directions`, as in `pipe_directions`.
+fn resolve_start(directions: &[GridDirection]) -> Option<char> {
+    ['|', '-', 'L', 'J', '7', 'F']
+        .into_iter()
+        .find(|pipe| pipe_directions(*pipe) == directions)
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::Day10;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day10>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day10>(file!(), "input_test_2", 2);
+    }
+}
@@ -0,0 +1,148 @@
+use utils::{grid::Grid, parse_into_char_vector_vector, solution::Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse_into_char_vector_vector(input_file)
+            .try_into()
+            .unwrap()
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input.clone())
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn run(mut grid: Grid<char>) -> usize {
+    // Solve
+    // Expand
+    let mut y = 0;
+    while y < grid.get_max_y() {
+        if !grid.row(y).contains(&'#') {
+            grid.insert_row(y, grid.row(y).to_vec());
+            y += 2;
+            continue;
+        }
+        y += 1;
+    }
+
+    let mut x = 0;
+    while x < grid.get_max_x() {
+        if grid.column(x).any(|c| c == &'#') {
+            x += 1;
+            continue;
+        }
+
+        grid.insert_column(x, '.');
+
+        x += 2;
+    }
+
+    // grid.print_data();
+
+    // Solution
+    let stars = grid.get_all_positions_for(&'#');
+
+    let mut result = 0;
+    for (i, s1) in stars.iter().enumerate() {
+        for s2 in stars.iter().skip(i) {
+            result += s1.manhatten_distance(s2);
+        }
+    }
+
+    // Result
+    result
+}
+
+fn run2(grid: &Grid<char>) -> usize {
+    // Preamble
+    #[cfg(test)]
+    const EXPANSION_VALUE: usize = 100;
+
+    #[cfg(not(test))]
+    const EXPANSION_VALUE: usize = 1_000_000;
+
+    let mut distant_grid: Grid<usize> =
+        Grid::with_width_height(grid.get_max_x(), grid.get_max_y(), 1);
+
+    // Solve
+    // Expand
+    'row: for (y, row) in grid.rows().enumerate() {
+        for column in row.iter() {
+            if column == &'#' {
+                continue 'row;
+            }
+        }
+
+        for column in distant_grid.row_mut(y).iter_mut() {
+            *column = EXPANSION_VALUE;
+        }
+    }
+
+    let max_x = grid.get_max_x();
+    let max_y = grid.get_max_y();
+
+    'column: for x in 0..max_x {
+        if grid.column(x).any(|c| c == &'#') {
+            continue 'column;
+        }
+        for y in 0..max_y {
+            distant_grid.set(x, y, EXPANSION_VALUE);
+        }
+    }
+
+    // Solution
+    let stars = grid.get_all_positions_for(&'#');
+
+    let mut results = Vec::new();
+
+    for (i, s1) in stars.iter().enumerate() {
+        for s2 in stars.iter().skip(i) {
+            let min_x = s1.x.min(s2.x);
+            let min_y = s1.y.min(s2.y);
+            let max_x = s1.x.max(s2.x);
+            let max_y = s1.y.max(s2.y);
+            let mut result: usize = 0;
+
+            for x in min_x..max_x {
+                result += distant_grid.row(min_y)[x];
+            }
+
+            for y in min_y..max_y {
+                result += distant_grid.row(y)[max_x];
+            }
+
+            results.push(result);
+        }
+    }
+
+    // distant_grid.print_data();
+    // Result
+    results.iter().sum::<usize>()
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::Day11;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day11>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day11>(file!(), "input_test", 2);
+    }
+}
@@ -0,0 +1,183 @@
+use utils::{
+    cycle::detect_cycle, grid::Grid, grid_point::GridPoint, parse_into_char_vector_vector,
+    solution::Solution,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse_into_char_vector_vector(input_file)
+            .try_into()
+            .unwrap()
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input.clone())
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn tilt_south(values: &mut Grid<char>) {
+    let mut swap_point = GridPoint::default();
+    let mut needs_new_swappoint;
+    for x in 0..values.get_max_x() {
+        needs_new_swappoint = true;
+        for y in (0..values.get_max_y()).rev() {
+            if values.row(y)[x] == '#' {
+                needs_new_swappoint = true;
+                continue;
+            }
+            if needs_new_swappoint && values.row(y)[x] == '.' {
+                swap_point = GridPoint::new(x, y);
+                needs_new_swappoint = false;
+                continue;
+            }
+
+            if !needs_new_swappoint && values.row(y)[x] == 'O' {
+                values.swap(swap_point.x, swap_point.y, x, y);
+                swap_point.move_up();
+            }
+        }
+    }
+}
+
+fn tilt_west(values: &mut Grid<char>) {
+    let mut swap_point = GridPoint::default();
+    let mut needs_new_swappoint;
+    for y in 0..values.get_max_y() {
+        needs_new_swappoint = true;
+        for x in (0..values.get_max_x()).rev() {
+            if values.row(y)[x] == '#' {
+                needs_new_swappoint = true;
+                continue;
+            }
+            if needs_new_swappoint && values.row(y)[x] == '.' {
+                swap_point = GridPoint::new(x, y);
+                needs_new_swappoint = false;
+                continue;
+            }
+
+            if !needs_new_swappoint && values.row(y)[x] == 'O' {
+                values.swap(swap_point.x, swap_point.y, x, y);
+                swap_point.move_left();
+            }
+        }
+    }
+}
+
+fn tilt_east(values: &mut Grid<char>) {
+    let mut swap_point = GridPoint::default();
+    let mut needs_new_swappoint;
+    for y in 0..values.get_max_y() {
+        needs_new_swappoint = true;
+        for x in 0..values.get_max_x() {
+            if values.row(y)[x] == '#' {
+                needs_new_swappoint = true;
+                continue;
+            }
+            if needs_new_swappoint && values.row(y)[x] == '.' {
+                swap_point = GridPoint::new(x, y);
+                needs_new_swappoint = false;
+                continue;
+            }
+
+            if !needs_new_swappoint && values.row(y)[x] == 'O' {
+                values.swap(swap_point.x, swap_point.y, x, y);
+                swap_point.move_right();
+            }
+        }
+    }
+}
+
+fn tilt_north(values: &mut Grid<char>) {
+    let mut swap_point = GridPoint::default();
+    let mut needs_new_swappoint;
+    for x in 0..values.get_max_x() {
+        needs_new_swappoint = true;
+        for y in 0..values.get_max_y() {
+            if values.row(y)[x] == '#' {
+                needs_new_swappoint = true;
+                continue;
+            }
+            if needs_new_swappoint && values.row(y)[x] == '.' {
+                swap_point = GridPoint::new(x, y);
+                needs_new_swappoint = false;
+                continue;
+            }
+
+            if !needs_new_swappoint && values.row(y)[x] == 'O' {
+                values.swap(swap_point.x, swap_point.y, x, y);
+                swap_point.move_down();
+            }
+        }
+    }
+}
+
+fn run(mut values: Grid<char>) -> usize {
+    // Solve
+    tilt_north(&mut values);
+
+    // Result
+    // values.print_data();
+    let rocks = values.get_all_positions_for(&'O');
+    let mut result = 0;
+    let max_y = values.get_max_y();
+    for rock in rocks {
+        result += max_y - rock.y;
+    }
+
+    result
+}
+
+fn spin_cycle(values: &Grid<char>) -> Grid<char> {
+    let mut values = values.clone();
+    tilt_north(&mut values);
+    tilt_east(&mut values);
+    tilt_south(&mut values);
+    tilt_west(&mut values);
+    values
+}
+
+fn run2(values: &Grid<char>) -> usize {
+    // Preamble
+    const ROUNDS: usize = 1_000_000_000;
+
+    // Solve
+    let values = detect_cycle(values.clone(), spin_cycle, ROUNDS).state;
+
+    // Result
+    // values.print_data();
+    let rocks = values.get_all_positions_for(&'O');
+    let mut result = 0;
+    let max_y = values.get_max_y();
+    for rock in rocks {
+        result += max_y - rock.y;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::Day14;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day14>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day14>(file!(), "input_test", 2);
+    }
+}
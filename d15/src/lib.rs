@@ -0,0 +1,162 @@
+use std::fs;
+
+use utils::solution::Solution;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        fs::read_to_string(input_file)
+            .unwrap()
+            .split(',')
+            .map(|f| f.trim().into())
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn run(items: &[String]) -> usize {
+    // Preamble
+    let mut result: usize = 0;
+
+    // Solve
+
+    for hash in items.iter() {
+        let mut hash_value: usize = 0;
+        for c in hash.as_bytes().iter() {
+            hash_value += *c as usize;
+            hash_value *= 17;
+            hash_value %= 256;
+        }
+        result += hash_value;
+    }
+
+    // Result
+    result
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Dash,
+    Equils,
+}
+
+#[derive(Debug, Clone)]
+struct Item {
+    value: String,
+    label: String,
+    operation: Operation,
+    hash: u8,
+}
+impl Item {
+    fn get_focal_length(&self) -> usize {
+        self.value.chars().last().unwrap().to_digit(10).unwrap() as usize
+    }
+}
+
+impl From<&str> for Item {
+    fn from(value: &str) -> Self {
+        let operation = if value.ends_with('-') {
+            Operation::Dash
+        } else {
+            Operation::Equils
+        };
+
+        let label = match operation {
+            Operation::Dash => value[0..value.len() - 1].to_string(),
+            Operation::Equils => value[0..value.len() - 2].to_string(),
+        };
+
+        let mut hash_value: usize = 0;
+        for c in label.as_bytes().iter() {
+            hash_value += *c as usize;
+            hash_value *= 17;
+            hash_value %= 256;
+        }
+
+        Self {
+            value: value.into(),
+            label,
+            operation,
+            hash: hash_value as u8,
+        }
+    }
+}
+
+fn run2(items: &[String]) -> usize {
+    // Preamble
+    let mut result: usize = 0;
+    let mut boxes: Vec<Vec<Item>> = vec![Vec::new(); 256];
+    // Parse
+    let items: Vec<Item> = items.iter().map(|f| f.as_str().into()).collect();
+
+    // Solve
+    for item in items {
+        match item.operation {
+            Operation::Dash => dash_operation(&mut boxes, item),
+            Operation::Equils => equils_operation(&mut boxes, item),
+        }
+    }
+
+    // Result
+    for (box_slot, b) in boxes.iter().enumerate() {
+        for (item_slot, item) in b.iter().enumerate() {
+            result += (box_slot + 1) * (item_slot + 1) * item.get_focal_length();
+        }
+    }
+
+    result
+}
+
+fn equils_operation(boxes: &mut [Vec<Item>], item: Item) {
+    let current_box = &mut boxes[item.hash as usize];
+
+    if let Some((idx, _)) = current_box
+        .iter()
+        .enumerate()
+        .find(|i| i.1.label == item.label)
+    {
+        current_box[idx] = item;
+    } else {
+        current_box.push(item);
+    }
+}
+
+fn dash_operation(boxes: &mut [Vec<Item>], item: Item) {
+    let current_box = &mut boxes[item.hash as usize];
+    if let Some((idx, _)) = current_box
+        .iter()
+        .enumerate()
+        .find(|i| i.1.label == item.label)
+    {
+        current_box.remove(idx);
+    }
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::Day15;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day15>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day15>(file!(), "input_test", 2);
+    }
+}
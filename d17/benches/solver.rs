@@ -0,0 +1,26 @@
+// Parse and both parts of the real solver on the example input, run with `cargo bench -p d17`.
+use d17::Day17;
+use utils::{
+    bench::{bench, BenchConfig, Stats},
+    get_test_input_path,
+};
+
+fn print(stage: &str, stats: &Stats) {
+    println!(
+        "{:<7} min {:>9.3} ms   median {:>9.3} ms   p95 {:>9.3} ms",
+        stage,
+        stats.min.as_secs_f64() * 1000.0,
+        stats.median.as_secs_f64() * 1000.0,
+        stats.p95.as_secs_f64() * 1000.0
+    );
+}
+
+fn main() {
+    let input_path = get_test_input_path(file!());
+    let result = bench::<Day17>(input_path.to_str().unwrap(), &BenchConfig::default());
+
+    println!("Day 17 on {}", input_path.display());
+    print("parse", &result.parse);
+    print("part_1", &result.part_1);
+    print("part_2", &result.part_2);
+}
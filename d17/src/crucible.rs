@@ -0,0 +1,212 @@
+use utils::{
+    grid::Grid,
+    grid_direction::GridDirection,
+    grid_point::GridPoint,
+    search::{dijkstra, dijkstra_distances},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+    Back,
+}
+
+impl Turn {
+    pub fn apply(&self, direction: GridDirection) -> GridDirection {
+        match self {
+            Turn::Left => direction.turn_left(),
+            Turn::Right => direction.turn_right(),
+            Turn::Back => direction.opposite(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrucibleRules {
+    // Blocks to move straight before turning or stopping at the goal.
+    pub min_straight: usize,
+    pub max_straight: usize,
+    pub turns: Vec<Turn>,
+}
+
+impl CrucibleRules {
+    pub fn new(min_straight: usize, max_straight: usize) -> Self {
+        assert!(min_straight <= max_straight && max_straight > 0);
+        Self {
+            min_straight,
+            max_straight,
+            turns: vec![Turn::Left, Turn::Right],
+        }
+    }
+
+    pub fn with_turns(mut self, turns: &[Turn]) -> Self {
+        self.turns = turns.to_vec();
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Step {
+    pub position: GridPoint,
+    // Direction the crucible moved in to enter the block.
+    pub direction: GridDirection,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub heat_loss: usize,
+    // Every block after the start, the start itself costs nothing.
+    pub path: Vec<Step>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct CrucibleState {
+    position: GridPoint,
+    direction: GridDirection,
+    // Blocks moved in `direction` so far.
+    straight: usize,
+}
+
+impl CrucibleState {
+    fn successors(&self, grid: &Grid<usize>, rules: &CrucibleRules) -> Vec<(CrucibleState, usize)> {
+        let mut moves = Vec::with_capacity(rules.turns.len() + 1);
+        if self.straight < rules.max_straight {
+            moves.push((self.direction, self.straight + 1));
+        }
+        if self.straight >= rules.min_straight {
+            moves.extend(rules.turns.iter().map(|t| (t.apply(self.direction), 1)));
+        }
+
+        moves
+            .into_iter()
+            .filter_map(|(direction, straight)| {
+                let position = self.position.next_by_direction_with_check(
+                    &direction,
+                    grid.get_max_x(),
+                    grid.get_max_y(),
+                )?;
+                let heat_loss = *grid.get_from_point(&position).unwrap();
+                Some((
+                    CrucibleState {
+                        position,
+                        direction,
+                        straight,
+                    },
+                    heat_loss,
+                ))
+            })
+            .collect()
+    }
+}
+
+fn start_states(start: GridPoint) -> [CrucibleState; 4] {
+    // Not moved yet, so the first run may go any direction.
+    GridDirection::get_directions().map(|direction| CrucibleState {
+        position: start,
+        direction,
+        straight: 0,
+    })
+}
+
+pub fn find_route(
+    grid: &Grid<usize>,
+    rules: &CrucibleRules,
+    start: GridPoint,
+    goal: GridPoint,
+) -> Option<Route> {
+    // Already there, no need to move at all.
+    if start == goal {
+        return Some(Route {
+            heat_loss: 0,
+            path: Vec::new(),
+        });
+    }
+
+    let result = dijkstra(
+        start_states(start),
+        |state| state.successors(grid, rules),
+        |state| state.position == goal && state.straight >= rules.min_straight,
+    )?;
+
+    Some(Route {
+        heat_loss: result.cost,
+        path: result
+            .path
+            .iter()
+            .skip(1)
+            .map(|s| Step {
+                position: s.position,
+                direction: s.direction,
+            })
+            .collect(),
+    })
+}
+
+// Lowest heat loss to stop on every block, None where the rules never allow stopping.
+pub fn cost_map(
+    grid: &Grid<usize>,
+    rules: &CrucibleRules,
+    start: GridPoint,
+) -> Grid<Option<usize>> {
+    let distances = dijkstra_distances(start_states(start), |state| state.successors(grid, rules));
+
+    let mut rtn = Grid::with_width_height(grid.get_max_x(), grid.get_max_y(), None);
+    rtn.set_from_point(&start, Some(0));
+    for (state, cost) in distances {
+        if state.straight < rules.min_straight {
+            continue;
+        }
+        let best = rtn.get_from_point(&state.position).unwrap();
+        if best.is_none_or(|best| cost < best) {
+            rtn.set_from_point(&state.position, Some(cost));
+        }
+    }
+    rtn
+}
+
+// The heat loss digits with the route drawn over them.
+pub fn render_route(grid: &Grid<usize>, route: &Route) -> Grid<char> {
+    let digits = grid
+        .as_slice()
+        .iter()
+        .map(|v| char::from_digit(*v as u32, 10).unwrap_or('#'))
+        .collect();
+    let mut rtn = Grid::from_vec(grid.get_max_x(), grid.get_max_y(), digits).unwrap();
+    for step in route.path.iter() {
+        rtn.set_from_point(&step.position, step.direction.to_char());
+    }
+    rtn
+}
+
+// Plain PGM image, cheap blocks dark, expensive and unreachable ones white.
+pub fn cost_map_to_pgm(costs: &Grid<Option<usize>>) -> String {
+    const MAX_VALUE: usize = 65535;
+
+    let highest = costs
+        .as_slice()
+        .iter()
+        .flatten()
+        .max()
+        .copied()
+        .unwrap_or(0)
+        .max(1);
+    let max_value = highest.min(MAX_VALUE);
+    let scale = |cost: usize| cost * max_value / highest;
+
+    let mut rtn = format!(
+        "P2\n{} {}\n{}\n",
+        costs.get_max_x(),
+        costs.get_max_y(),
+        max_value
+    );
+    for row in costs.rows() {
+        let values: Vec<String> = row
+            .iter()
+            .map(|c| c.map_or(max_value, scale).to_string())
+            .collect();
+        rtn.push_str(&values.join(" "));
+        rtn.push('\n');
+    }
+    rtn
+}
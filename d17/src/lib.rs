@@ -0,0 +1,167 @@
+use crucible::{find_route, CrucibleRules};
+use utils::{
+    grid::Grid, grid_point::GridPoint, parse_into_usize_vector_vector_by_char, solution::Solution,
+};
+
+pub mod crucible;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse_into_usize_vector_vector_by_char(input_file)
+            .try_into()
+            .unwrap()
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn run(grid: &Grid<usize>) -> usize {
+    let goal = GridPoint::new(grid.get_max_x() - 1, grid.get_max_y() - 1);
+
+    // Solve
+    let route = find_route(grid, &CrucibleRules::new(1, 3), GridPoint::new(0, 0), goal);
+
+    // Result
+    route.expect("No solution found.").heat_loss
+}
+
+fn run2(grid: &Grid<usize>) -> usize {
+    let goal = GridPoint::new(grid.get_max_x() - 1, grid.get_max_y() - 1);
+
+    // Solve
+    let route = find_route(grid, &CrucibleRules::new(4, 10), GridPoint::new(0, 0), goal);
+
+    // Result
+    route.expect("No solution found.").heat_loss
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+    use utils::get_test_input_path;
+    use utils::grid::Grid;
+    use utils::grid_direction::GridDirection;
+    use utils::grid_point::GridPoint;
+    use utils::solution::Solution;
+
+    use crate::crucible::{
+        cost_map, cost_map_to_pgm, find_route, render_route, CrucibleRules, Turn,
+    };
+    use crate::Day17;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day17>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day17>(file!(), "input_test", 2);
+    }
+
+    #[test]
+    fn test_input_part_3() {
+        check_answer::<Day17>(file!(), "input_test_2", 2);
+    }
+
+    #[test]
+    fn test_route() {
+        let input_path = get_test_input_path(file!());
+        let grid = Day17::parse(input_path.to_str().unwrap());
+        let start = GridPoint::new(0, 0);
+        let goal = GridPoint::new(grid.get_max_x() - 1, grid.get_max_y() - 1);
+
+        let route = find_route(&grid, &CrucibleRules::new(1, 3), start, goal).unwrap();
+        assert_eq!(route.path.last().unwrap().position, goal);
+        assert_eq!(
+            route
+                .path
+                .iter()
+                .map(|s| grid.get_from_point(&s.position).unwrap())
+                .sum::<usize>(),
+            route.heat_loss
+        );
+        for pair in route.path.windows(2) {
+            assert_eq!(
+                pair[0].position.next_by_direction(&pair[1].direction),
+                pair[1].position
+            );
+        }
+
+        // Backwards from the goal to the start.
+        let back = find_route(&grid, &CrucibleRules::new(1, 3), goal, start).unwrap();
+        assert_eq!(back.path.last().unwrap().position, start);
+    }
+
+    #[test]
+    fn test_u_turns() {
+        // A corridor longer than the crucible may go straight, only turning back gets through.
+        let grid: Grid<usize> = vec![vec![1, 1, 1, 1, 1]].try_into().unwrap();
+        let start = GridPoint::new(0, 0);
+        let goal = GridPoint::new(4, 0);
+
+        let rules = CrucibleRules::new(1, 3);
+        assert_eq!(find_route(&grid, &rules, start, goal), None);
+
+        let rules = rules.with_turns(&[Turn::Left, Turn::Right, Turn::Back]);
+        let route = find_route(&grid, &rules, start, goal).unwrap();
+        assert_eq!(route.heat_loss, 6);
+        assert_eq!(route.path.len(), 6);
+        assert_eq!(route.path.last().unwrap().position, goal);
+        assert!(route
+            .path
+            .iter()
+            .any(|s| s.direction == GridDirection::Left));
+    }
+
+    #[test]
+    fn test_route_to_start() {
+        let grid: Grid<usize> = vec![vec![1, 9, 1], vec![1, 9, 9]].try_into().unwrap();
+        let start = GridPoint::new(0, 0);
+        for rules in [CrucibleRules::new(1, 3), CrucibleRules::new(4, 10)] {
+            let route = find_route(&grid, &rules, start, start).unwrap();
+            assert_eq!(route.heat_loss, 0);
+            assert!(route.path.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_render_route() {
+        let grid: Grid<usize> = vec![vec![1, 9], vec![1, 1]].try_into().unwrap();
+        let rules = CrucibleRules::new(1, 3);
+        let start = GridPoint::new(0, 0);
+        let route = find_route(&grid, &rules, start, GridPoint::new(1, 1)).unwrap();
+        assert_eq!(render_route(&grid, &route).grid_to_string(), "19\nv>\n");
+
+        let costs = cost_map(&grid, &rules, start);
+        assert_eq!(costs.as_slice(), &[Some(0), Some(9), Some(1), Some(2)]);
+        assert_eq!(cost_map_to_pgm(&costs), "P2\n2 2\n9\n0 9\n1 2\n");
+    }
+
+    #[test]
+    fn test_cost_map() {
+        let input_path = get_test_input_path(file!());
+        let grid = Day17::parse(input_path.to_str().unwrap());
+        let rules = CrucibleRules::new(4, 10);
+        let costs = cost_map(&grid, &rules, GridPoint::new(0, 0));
+        let goal = GridPoint::new(grid.get_max_x() - 1, grid.get_max_y() - 1);
+
+        assert_eq!(*costs.get_from_point(&goal).unwrap(), Some(94));
+        // Stopping needs four straight blocks, so the block next to the start is only reached
+        // by coming back around.
+        assert!(costs.get(1, 0).unwrap().unwrap() > *grid.get(1, 0).unwrap());
+        assert!(cost_map_to_pgm(&costs).starts_with("P2\n13 13\n"));
+    }
+}
@@ -0,0 +1,142 @@
+use utils::{
+    hash_point_map::HashPointMap,
+    map_direction::MapDirection,
+    parse_file_into,
+    point::{calculate_area, MapPoint},
+    solution::Solution,
+};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse_file_into(input_file)
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    direction: MapDirection,
+    steps: usize,
+    color: String,
+}
+impl Instruction {
+    fn get_steps_from_hex(&self) -> i64 {
+        i64::from_str_radix(&self.color[0..5], 16).unwrap()
+    }
+
+    fn get_direction_from_hex(&self) -> MapDirection {
+        match self.color.chars().last().unwrap().to_digit(10).unwrap() {
+            0 => MapDirection::Right,
+            1 => MapDirection::Down,
+            2 => MapDirection::Left,
+            3 => MapDirection::Up,
+            _ => panic!("Should not be here!"),
+        }
+    }
+}
+
+impl From<String> for Instruction {
+    fn from(value: String) -> Self {
+        let direction = value.chars().next().unwrap().into();
+        let mut split = value.split(' ');
+        let steps = split.nth(1).unwrap().parse().unwrap();
+        let color_part = split.next().unwrap();
+        let color = color_part[2..color_part.len() - 1].to_string();
+
+        Self {
+            direction,
+            steps,
+            color,
+        }
+    }
+}
+
+fn run(instructions: &[Instruction]) -> usize {
+    // Preamble
+    let mut position = MapPoint::new(0, 0);
+    let mut map = HashPointMap::default();
+
+    for instruction in instructions {
+        for _ in 0..instruction.steps {
+            map.insert(position, '#');
+            position.move_by_direction(&instruction.direction);
+        }
+    }
+
+    // Solve
+    let (min_x, max_x, min_y, max_y) = map.get_min_x_max_x_min_y_max_y();
+
+    for y in min_y..=max_y {
+        let mut fill = false;
+        for x in min_x..=max_x {
+            let has_hole = map.get_x_y(x, y).is_some();
+            let is_wall = has_hole && map.get_x_y(x, y + 1).is_some();
+
+            if is_wall {
+                fill = !fill;
+            }
+
+            if fill {
+                map.insert(MapPoint::new(x, y), '#');
+            }
+        }
+    }
+
+    // map.print_all('.');
+
+    // Result
+    map.len()
+}
+
+fn run2(instructions: &[Instruction]) -> i64 {
+    // Preamble
+    let mut position = MapPoint::new(0, 0);
+    // let mut points = vec![position];
+    let mut points = Vec::new();
+
+    let mut steps_count = 0;
+
+    for instruction in instructions {
+        let direction = instruction.get_direction_from_hex();
+        let steps = instruction.get_steps_from_hex();
+
+        steps_count += steps;
+        position.move_by_direction_and_distance(direction, steps);
+        points.push(position);
+    }
+
+    let result = calculate_area(&points) + steps_count / 2 + 1;
+
+    // Result
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::Day18;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day18>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day18>(file!(), "input_test", 2);
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use utils::interval::Interval;
+
+use crate::workflow::{Region, System, Target, START};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Analysis {
+    // Workflows no rating in the domains can reach from `in`.
+    pub unreachable: Vec<String>,
+    // Workflow and rule index of rules that earlier rules of the same workflow leave nothing for.
+    pub dead_rules: Vec<(String, usize)>,
+    pub always_accept: Vec<String>,
+    pub always_reject: Vec<String>,
+}
+
+impl Analysis {
+    pub fn new(system: &System, domains: &[Interval<usize>]) -> Self {
+        let mut names: Vec<&String> = system.workflows.keys().collect();
+        names.sort();
+
+        let mut reached: HashSet<&str> = HashSet::from([START]);
+        system.walk(
+            START,
+            Region::new(domains.to_vec()),
+            |workflow, index, _| {
+                if let Target::Workflow(next) = &workflow.rules[index].target {
+                    reached.insert(next);
+                }
+            },
+        );
+
+        let mut rtn = Self::default();
+        let volume = Region::new(domains.to_vec()).volume();
+        for name in names {
+            if !reached.contains(name.as_str()) {
+                rtn.unreachable.push(name.clone());
+            }
+
+            let mut remaining = vec![Region::new(domains.to_vec())];
+            for (index, rule) in system.workflows[name].rules.iter().enumerate() {
+                let mut fired = false;
+                let mut unmatched = Vec::new();
+                for region in remaining {
+                    let (matched, mut rest) = rule.split(&region);
+                    fired |= matched.is_some();
+                    unmatched.append(&mut rest);
+                }
+                if !fired {
+                    rtn.dead_rules.push((name.clone(), index));
+                }
+                remaining = unmatched;
+            }
+
+            let accepted: usize = system
+                .accepted_regions_from(name, domains)
+                .iter()
+                .map(|r| r.volume())
+                .sum();
+            if accepted == volume {
+                rtn.always_accept.push(name.clone());
+            } else if accepted == 0 {
+                rtn.always_reject.push(name.clone());
+            }
+        }
+
+        rtn
+    }
+
+    pub fn is_dead(&self, workflow: &str, index: usize) -> bool {
+        self.dead_rules
+            .iter()
+            .any(|(w, i)| w == workflow && *i == index)
+    }
+
+    // Graphviz graph of the workflows, edges labelled with their rule's condition. Dead rules are
+    // dashed, unreachable workflows grey and workflows with a fixed outcome filled.
+    pub fn to_dot(&self, system: &System) -> String {
+        let mut names: Vec<&String> = system.workflows.keys().collect();
+        names.sort();
+
+        let mut rtn = String::from("digraph workflows {\n");
+        rtn.push_str("    \"A\" [shape=doublecircle];\n");
+        rtn.push_str("    \"R\" [shape=doublecircle];\n");
+
+        for name in names.iter() {
+            let mut attributes = Vec::new();
+            if self.unreachable.contains(name) {
+                attributes.push("color=grey, fontcolor=grey");
+            }
+            if self.always_accept.contains(name) {
+                attributes.push("style=filled, fillcolor=palegreen");
+            } else if self.always_reject.contains(name) {
+                attributes.push("style=filled, fillcolor=lightpink");
+            }
+            if attributes.is_empty() {
+                writeln!(rtn, "    \"{}\";", name).unwrap();
+            } else {
+                writeln!(rtn, "    \"{}\" [{}];", name, attributes.join(", ")).unwrap();
+            }
+        }
+
+        for name in names {
+            for (index, rule) in system.workflows[name].rules.iter().enumerate() {
+                let label = match &rule.condition {
+                    Some(condition) => condition.to_string(),
+                    None => "else".to_string(),
+                };
+                let style = if self.is_dead(name, index) {
+                    ", style=dashed"
+                } else {
+                    ""
+                };
+                writeln!(
+                    rtn,
+                    "    \"{}\" -> \"{}\" [label=\"{}\"{}];",
+                    name, rule.target, label, style
+                )
+                .unwrap();
+            }
+        }
+
+        rtn.push_str("}\n");
+        rtn
+    }
+}
@@ -0,0 +1,166 @@
+use std::fs;
+
+use utils::solution::Solution;
+use workflow::{System, DEFAULT_DOMAIN};
+
+pub mod analysis;
+pub mod workflow;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        fs::read_to_string(input_file)
+            .unwrap()
+            .parse()
+            .unwrap_or_else(|e| panic!("Could not parse {}: {}", input_file, e))
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn run(system: &System) -> usize {
+    // Solve
+    // Result
+    system
+        .ratings
+        .iter()
+        .filter(|r| system.accepts(r))
+        .map(|r| r.sum())
+        .sum()
+}
+
+fn run2(system: &System) -> usize {
+    // Solve
+    // Result
+    system.count_accepted(&vec![DEFAULT_DOMAIN; system.attributes.len()])
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+    use utils::get_test_input_path;
+    use utils::interval::Interval;
+    use utils::solution::Solution;
+
+    use crate::analysis::Analysis;
+    use crate::workflow::{System, SystemError, DEFAULT_DOMAIN};
+    use crate::Day19;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day19>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day19>(file!(), "input_test", 2);
+    }
+
+    #[test]
+    fn test_trace() {
+        let input_path = get_test_input_path(file!());
+        let system = Day19::parse(input_path.to_str().unwrap());
+        assert_eq!(system.attributes, vec!["x", "m", "a", "s"]);
+
+        let trace = system.trace(&system.ratings[0]);
+        assert!(trace.accepted);
+        assert_eq!(
+            trace.to_string(),
+            "in[1] qqz -> qqz[0] s>2770:qs -> qs[1] lnx -> lnx[0] m>1548:A -> A"
+        );
+        assert!(!system.accepts(&system.ratings[1]));
+    }
+
+    #[test]
+    fn test_operators_and_domains() {
+        let system: System = "in{b==3:R,a<=2:A,b>=4:A,R}\n".parse().unwrap();
+        assert_eq!(system.attributes, vec!["b", "a"]);
+
+        // b in 1..=5, a in 1..=3: b == 3 rejects, then a <= 2 or b >= 4 accepts.
+        let domains = [Interval::inclusive(1, 5), Interval::inclusive(1, 3)];
+        assert_eq!(system.count_accepted(&domains), 4 * 2 + 2);
+
+        // Conditions on the largest value clamp their bound instead of overflowing.
+        let system: System = format!("in{{a<={0}:A,a>{0}:A,a=={0}:A,R}}\n", usize::MAX)
+            .parse()
+            .unwrap();
+        let intervals: Vec<Interval<usize>> = system.workflows["in"]
+            .rules
+            .iter()
+            .filter_map(|rule| rule.condition.as_ref().map(|c| c.interval()))
+            .collect();
+        assert_eq!(intervals[0], Interval::new(0, usize::MAX));
+        assert!(intervals[1].is_empty());
+        assert!(intervals[2].is_empty());
+    }
+
+    #[test]
+    fn test_invalid_systems() {
+        let error = |content: &str| content.parse::<System>().unwrap_err();
+
+        assert_eq!(
+            error("in{x<5:px,A}\npx{x>2:in,R}\n\n{x=1}"),
+            SystemError::Cycle(vec!["in".into(), "px".into(), "in".into()])
+        );
+        assert_eq!(
+            error("in{x<5:px,A}\n\n{x=1}"),
+            SystemError::UndefinedWorkflow {
+                workflow: "in".into(),
+                target: "px".into()
+            }
+        );
+        assert_eq!(
+            error("in{y<5:A,R}\n\n{x=1}"),
+            SystemError::UnknownAttribute {
+                workflow: "in".into(),
+                attribute: "y".into()
+            }
+        );
+        assert_eq!(
+            error("in{x<5:A}"),
+            SystemError::MissingFallback {
+                workflow: "in".into()
+            }
+        );
+        assert_eq!(error("px{A}"), SystemError::MissingStart);
+    }
+
+    #[test]
+    fn test_analysis() {
+        let input_path = get_test_input_path(file!());
+        let system = Day19::parse(input_path.to_str().unwrap());
+        let analysis = Analysis::new(&system, &[DEFAULT_DOMAIN; 4]);
+        assert!(analysis.unreachable.is_empty());
+        assert!(analysis.dead_rules.is_empty());
+        assert_eq!(analysis.always_accept, vec!["lnx", "qs"]);
+        assert_eq!(analysis.always_reject, vec!["gd"]);
+
+        let system: System = "in{x<5:A,x<3:R,x>=5:R,px}\npx{x>10:A,R}\n\n{x=1}"
+            .parse()
+            .unwrap();
+        let analysis = Analysis::new(&system, &[DEFAULT_DOMAIN]);
+        assert_eq!(analysis.unreachable, vec!["px"]);
+        assert_eq!(
+            analysis.dead_rules,
+            vec![("in".to_string(), 1), ("in".to_string(), 3)]
+        );
+
+        let dot = analysis.to_dot(&system);
+        assert!(dot.starts_with("digraph workflows {\n"));
+        assert!(dot.contains("    \"px\" [color=grey, fontcolor=grey];\n"));
+        assert!(dot.contains("    \"in\" -> \"A\" [label=\"x<5\"];\n"));
+        assert!(dot.contains("    \"in\" -> \"R\" [label=\"x<3\", style=dashed];\n"));
+        assert!(dot.contains("    \"in\" -> \"px\" [label=\"else\", style=dashed];\n"));
+    }
+}
@@ -0,0 +1,567 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use utils::interval::Interval;
+
+pub const START: &str = "in";
+
+// 1..=4000
+pub const DEFAULT_DOMAIN: Interval<usize> = Interval {
+    start: 1,
+    end: 4001,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SystemError {
+    Parse { line: usize, reason: String },
+    UnknownAttribute { workflow: String, attribute: String },
+    MissingStart,
+    UndefinedWorkflow { workflow: String, target: String },
+    MissingFallback { workflow: String },
+    Cycle(Vec<String>),
+}
+
+impl fmt::Display for SystemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SystemError::Parse { line, reason } => write!(f, "line {}: {}", line, reason),
+            SystemError::UnknownAttribute {
+                workflow,
+                attribute,
+            } => write!(
+                f,
+                "workflow {} uses attribute {} that no rating has",
+                workflow, attribute
+            ),
+            SystemError::MissingStart => write!(f, "no workflow named {}", START),
+            SystemError::UndefinedWorkflow { workflow, target } => write!(
+                f,
+                "workflow {} sends parts to undefined workflow {}",
+                workflow, target
+            ),
+            SystemError::MissingFallback { workflow } => {
+                write!(
+                    f,
+                    "workflow {} does not end with an unconditional rule",
+                    workflow
+                )
+            }
+            SystemError::Cycle(path) => write!(f, "workflows loop: {}", path.join(" -> ")),
+        }
+    }
+}
+
+impl std::error::Error for SystemError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operator {
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    Equal,
+}
+
+impl Operator {
+    // Two character operators first so that `<=` is not read as `<`.
+    const ALL: [Operator; 5] = [
+        Operator::LessEqual,
+        Operator::GreaterEqual,
+        Operator::Equal,
+        Operator::Less,
+        Operator::Greater,
+    ];
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Operator::Less => "<",
+            Operator::LessEqual => "<=",
+            Operator::Greater => ">",
+            Operator::GreaterEqual => ">=",
+            Operator::Equal => "==",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Condition {
+    pub name: String,
+    // Index into `System::attributes` and `Rating::values`.
+    pub attribute: usize,
+    pub operator: Operator,
+    pub value: usize,
+}
+
+impl Condition {
+    // All values the condition holds for. Intervals end at `usize::MAX` at the latest, so the bound
+    // after `value` is clamped there instead of overflowing.
+    pub fn interval(&self) -> Interval<usize> {
+        let after = self.value.saturating_add(1);
+        match self.operator {
+            Operator::Less => Interval::new(0, self.value),
+            Operator::LessEqual => Interval::new(0, after),
+            Operator::Greater => Interval::new(after, usize::MAX),
+            Operator::GreaterEqual => Interval::new(self.value, usize::MAX),
+            Operator::Equal => Interval::new(self.value, after),
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.name, self.operator.symbol(), self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Target {
+    Accept,
+    Reject,
+    Workflow(String),
+}
+
+impl From<&str> for Target {
+    fn from(value: &str) -> Self {
+        match value {
+            "A" => Target::Accept,
+            "R" => Target::Reject,
+            _ => Target::Workflow(value.into()),
+        }
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Target::Accept => write!(f, "A"),
+            Target::Reject => write!(f, "R"),
+            Target::Workflow(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+// One box of attribute values, one interval per attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Region {
+    pub axes: Vec<Interval<usize>>,
+}
+
+impl Region {
+    pub fn new(axes: Vec<Interval<usize>>) -> Self {
+        Self { axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|a| a.is_empty())
+    }
+
+    pub fn volume(&self) -> usize {
+        self.axes.iter().map(|a| a.len()).product()
+    }
+
+    fn with_axis(&self, axis: usize, interval: Interval<usize>) -> Self {
+        let mut axes = self.axes.clone();
+        axes[axis] = interval;
+        Self { axes }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rule {
+    // None for the unconditional last rule.
+    pub condition: Option<Condition>,
+    pub target: Target,
+}
+
+impl Rule {
+    pub fn matches(&self, rating: &Rating) -> bool {
+        self.condition
+            .as_ref()
+            .is_none_or(|c| c.interval().contains(rating.values[c.attribute]))
+    }
+
+    // Splits `region` into the part this rule sends to its target and the parts that fall
+    // through to the next rule.
+    pub fn split(&self, region: &Region) -> (Option<Region>, Vec<Region>) {
+        let Some(condition) = &self.condition else {
+            return (Some(region.clone()), Vec::new());
+        };
+
+        let axis = region.axes[condition.attribute];
+        let interval = condition.interval();
+        let matched = axis
+            .intersection(&interval)
+            .map(|i| region.with_axis(condition.attribute, i));
+        let unmatched = axis
+            .difference(&interval)
+            .into_iter()
+            .map(|i| region.with_axis(condition.attribute, i))
+            .collect();
+
+        (matched, unmatched)
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.condition {
+            Some(condition) => write!(f, "{}:{}", condition, self.target),
+            None => write!(f, "{}", self.target),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workflow {
+    pub name: String,
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rating {
+    // Ordered like `System::attributes`.
+    pub values: Vec<usize>,
+}
+
+impl Rating {
+    pub fn sum(&self) -> usize {
+        self.values.iter().sum()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step<'a> {
+    pub workflow: &'a str,
+    pub index: usize,
+    pub rule: &'a Rule,
+}
+
+// The rules a rating went through, one per visited workflow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace<'a> {
+    pub steps: Vec<Step<'a>>,
+    pub accepted: bool,
+}
+
+impl fmt::Display for Trace<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in self.steps.iter() {
+            write!(f, "{}[{}] {} -> ", step.workflow, step.index, step.rule)?;
+        }
+        write!(f, "{}", if self.accepted { "A" } else { "R" })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct System {
+    // Attribute names in the order the ratings list them.
+    pub attributes: Vec<String>,
+    pub workflows: HashMap<String, Workflow>,
+    pub ratings: Vec<Rating>,
+}
+
+fn parse_attributes(line: &str) -> Option<Vec<(&str, usize)>> {
+    line.strip_prefix('{')?
+        .strip_suffix('}')?
+        .split(',')
+        .map(|pair| {
+            let (name, value) = pair.split_once('=')?;
+            Some((name, value.parse().ok()?))
+        })
+        .collect()
+}
+
+fn parse_condition(text: &str) -> Option<(&str, Operator, usize)> {
+    let operator_start = text.find(['<', '>', '='])?;
+    let (name, rest) = text.split_at(operator_start);
+    let operator = Operator::ALL
+        .into_iter()
+        .find(|o| rest.starts_with(o.symbol()))?;
+    let value = rest[operator.symbol().len()..].parse().ok()?;
+    Some((name, operator, value))
+}
+
+impl FromStr for System {
+    type Err = SystemError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<(usize, &str)> = s
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| (idx + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty())
+            .collect();
+        let (rating_lines, workflow_lines): (Vec<_>, Vec<_>) = lines
+            .into_iter()
+            .partition(|(_, line)| line.starts_with('{'));
+
+        // Ratings first, they decide which attributes exist.
+        let mut attributes: Vec<String> = Vec::new();
+        let mut ratings = Vec::new();
+        for (line, text) in rating_lines {
+            let error = |reason: &str| SystemError::Parse {
+                line,
+                reason: format!("{} in \"{}\"", reason, text),
+            };
+            let pairs = parse_attributes(text).ok_or_else(|| error("invalid rating"))?;
+            if attributes.is_empty() {
+                attributes = pairs.iter().map(|(name, _)| name.to_string()).collect();
+            }
+
+            let mut values = vec![None; attributes.len()];
+            for (name, value) in pairs {
+                let index = attributes
+                    .iter()
+                    .position(|a| a == name)
+                    .ok_or_else(|| error("unexpected attribute"))?;
+                values[index] = Some(value);
+            }
+            let values = values
+                .into_iter()
+                .collect::<Option<_>>()
+                .ok_or_else(|| error("missing attribute"))?;
+            ratings.push(Rating { values });
+        }
+        let attributes_from_ratings = !ratings.is_empty();
+
+        let mut workflows = HashMap::new();
+        for (line, text) in workflow_lines {
+            let error = |reason: &str| SystemError::Parse {
+                line,
+                reason: format!("{} in \"{}\"", reason, text),
+            };
+            let (name, rules_text) = text
+                .strip_suffix('}')
+                .and_then(|t| t.split_once('{'))
+                .ok_or_else(|| error("invalid workflow"))?;
+
+            let mut rules = Vec::new();
+            for rule_text in rules_text.split(',') {
+                let Some((condition_text, target)) = rule_text.split_once(':') else {
+                    rules.push(Rule {
+                        condition: None,
+                        target: rule_text.into(),
+                    });
+                    continue;
+                };
+
+                let (attribute_name, operator, value) =
+                    parse_condition(condition_text).ok_or_else(|| error("invalid condition"))?;
+                let attribute = match attributes.iter().position(|a| a == attribute_name) {
+                    Some(attribute) => attribute,
+                    None if !attributes_from_ratings => {
+                        attributes.push(attribute_name.to_string());
+                        attributes.len() - 1
+                    }
+                    None => {
+                        return Err(SystemError::UnknownAttribute {
+                            workflow: name.to_string(),
+                            attribute: attribute_name.to_string(),
+                        })
+                    }
+                };
+
+                rules.push(Rule {
+                    condition: Some(Condition {
+                        name: attribute_name.to_string(),
+                        attribute,
+                        operator,
+                        value,
+                    }),
+                    target: target.into(),
+                });
+            }
+
+            workflows.insert(
+                name.to_string(),
+                Workflow {
+                    name: name.to_string(),
+                    rules,
+                },
+            );
+        }
+
+        let system = Self {
+            attributes,
+            workflows,
+            ratings,
+        };
+        system.validate()?;
+        Ok(system)
+    }
+}
+
+impl System {
+    pub fn validate(&self) -> Result<(), SystemError> {
+        if !self.workflows.contains_key(START) {
+            return Err(SystemError::MissingStart);
+        }
+
+        let mut names: Vec<&String> = self.workflows.keys().collect();
+        names.sort();
+
+        for name in names.iter() {
+            let workflow = &self.workflows[*name];
+            if workflow.rules.last().is_none_or(|r| r.condition.is_some()) {
+                return Err(SystemError::MissingFallback {
+                    workflow: workflow.name.clone(),
+                });
+            }
+            for rule in workflow.rules.iter() {
+                if let Target::Workflow(target) = &rule.target {
+                    if !self.workflows.contains_key(target) {
+                        return Err(SystemError::UndefinedWorkflow {
+                            workflow: workflow.name.clone(),
+                            target: target.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        // Depth first search, a workflow still on the path closes a loop.
+        let mut done: Vec<&str> = Vec::new();
+        for name in names {
+            let mut path: Vec<&str> = Vec::new();
+            self.find_cycle(name, &mut path, &mut done)?;
+        }
+
+        Ok(())
+    }
+
+    fn find_cycle<'a>(
+        &'a self,
+        name: &'a str,
+        path: &mut Vec<&'a str>,
+        done: &mut Vec<&'a str>,
+    ) -> Result<(), SystemError> {
+        if done.contains(&name) {
+            return Ok(());
+        }
+        if let Some(start) = path.iter().position(|n| *n == name) {
+            let mut cycle: Vec<String> = path[start..].iter().map(|n| n.to_string()).collect();
+            cycle.push(name.to_string());
+            return Err(SystemError::Cycle(cycle));
+        }
+
+        path.push(name);
+        for target in self.targets(name) {
+            self.find_cycle(target, path, done)?;
+        }
+        path.pop();
+        done.push(name);
+
+        Ok(())
+    }
+
+    // The workflows `name` can send parts to.
+    pub fn targets(&self, name: &str) -> impl Iterator<Item = &str> {
+        self.workflows[name]
+            .rules
+            .iter()
+            .filter_map(|r| match &r.target {
+                Target::Workflow(target) => Some(target.as_str()),
+                _ => None,
+            })
+    }
+
+    pub fn trace(&self, rating: &Rating) -> Trace<'_> {
+        let mut steps = Vec::new();
+        let mut current = START;
+
+        loop {
+            let workflow = &self.workflows[current];
+            // Validation guarantees an unconditional last rule.
+            let (index, rule) = workflow
+                .rules
+                .iter()
+                .enumerate()
+                .find(|(_, r)| r.matches(rating))
+                .unwrap();
+            steps.push(Step {
+                workflow: &workflow.name,
+                index,
+                rule,
+            });
+
+            match &rule.target {
+                Target::Accept => {
+                    return Trace {
+                        steps,
+                        accepted: true,
+                    }
+                }
+                Target::Reject => {
+                    return Trace {
+                        steps,
+                        accepted: false,
+                    }
+                }
+                Target::Workflow(next) => current = next,
+            }
+        }
+    }
+
+    pub fn accepts(&self, rating: &Rating) -> bool {
+        self.trace(rating).accepted
+    }
+
+    // Sends `region` through the workflows from `start`, `visit` sees every non empty part of it
+    // that a rule matches.
+    pub fn walk<'a>(
+        &'a self,
+        start: &'a str,
+        region: Region,
+        mut visit: impl FnMut(&'a Workflow, usize, &Region),
+    ) {
+        let mut pending = vec![(start, region)];
+
+        while let Some((name, region)) = pending.pop() {
+            let workflow = &self.workflows[name];
+            let mut remaining = vec![region];
+            for (index, rule) in workflow.rules.iter().enumerate() {
+                let mut unmatched = Vec::new();
+                for region in remaining {
+                    let (matched, mut rest) = rule.split(&region);
+                    unmatched.append(&mut rest);
+                    let Some(matched) = matched else {
+                        continue;
+                    };
+                    visit(workflow, index, &matched);
+                    if let Target::Workflow(next) = &rule.target {
+                        pending.push((next, matched));
+                    }
+                }
+                remaining = unmatched;
+            }
+        }
+    }
+
+    // Disjoint regions of `domains`, one interval per attribute, that `start` accepts.
+    pub fn accepted_regions_from(&self, start: &str, domains: &[Interval<usize>]) -> Vec<Region> {
+        assert_eq!(domains.len(), self.attributes.len());
+
+        let mut accepted = Vec::new();
+        self.walk(
+            start,
+            Region::new(domains.to_vec()),
+            |workflow, index, region| {
+                if workflow.rules[index].target == Target::Accept {
+                    accepted.push(region.clone());
+                }
+            },
+        );
+
+        accepted
+    }
+
+    pub fn accepted_regions(&self, domains: &[Interval<usize>]) -> Vec<Region> {
+        self.accepted_regions_from(START, domains)
+    }
+
+    pub fn count_accepted(&self, domains: &[Interval<usize>]) -> usize {
+        self.accepted_regions(domains)
+            .iter()
+            .map(|r| r.volume())
+            .sum()
+    }
+}
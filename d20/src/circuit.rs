@@ -0,0 +1,364 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+};
+
+pub type ModuleId = usize;
+
+pub const BUTTON: &str = "button";
+pub const BROADCASTER: &str = "broadcaster";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    Button,
+    Broadcast,
+    FlipFlop,
+    Conjunction,
+    // Only appears as a destination, e.g. `rx`.
+    Output,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Module {
+    pub name: String,
+    pub kind: Kind,
+    pub outputs: Vec<ModuleId>,
+    pub inputs: Vec<ModuleId>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pulse {
+    #[default]
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Event {
+    pub from: ModuleId,
+    pub to: ModuleId,
+    pub pulse: Pulse,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Circuit {
+    modules: Vec<Module>,
+    ids: HashMap<String, ModuleId>,
+    // For every output of a module, its position in the destination's inputs.
+    slots: Vec<Vec<usize>>,
+}
+
+impl FromStr for Circuit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut modules = vec![Module {
+            name: BUTTON.into(),
+            kind: Kind::Button,
+            outputs: Vec::new(),
+            inputs: Vec::new(),
+        }];
+        let mut ids = HashMap::from([(BUTTON.to_string(), 0)]);
+        let mut destinations = Vec::new();
+
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let error = |reason: &str| format!("line {}: {} in \"{}\"", idx + 1, reason, line);
+
+            let (module, outputs) = line
+                .split_once(" -> ")
+                .ok_or_else(|| error("expected \"module -> destinations\""))?;
+            let (kind, name) = match module.split_at(1) {
+                ("%", name) => (Kind::FlipFlop, name),
+                ("&", name) => (Kind::Conjunction, name),
+                _ if module == BROADCASTER => (Kind::Broadcast, module),
+                _ => return Err(error("unknown module type")),
+            };
+            if ids.insert(name.to_string(), modules.len()).is_some() {
+                return Err(error("module defined twice"));
+            }
+
+            modules.push(Module {
+                name: name.to_string(),
+                kind,
+                outputs: Vec::new(),
+                inputs: Vec::new(),
+            });
+            destinations.push(outputs.split(',').map(|d| d.trim()).collect::<Vec<_>>());
+        }
+
+        let Some(&broadcaster) = ids.get(BROADCASTER) else {
+            return Err(format!("no {} module", BROADCASTER));
+        };
+        modules[0].outputs.push(broadcaster);
+        modules[broadcaster].inputs.push(0);
+
+        for (from, outputs) in destinations.into_iter().enumerate() {
+            // Module 0 is the button.
+            let from = from + 1;
+            for output in outputs {
+                let to = *ids.entry(output.to_string()).or_insert_with(|| {
+                    modules.push(Module {
+                        name: output.to_string(),
+                        kind: Kind::Output,
+                        outputs: Vec::new(),
+                        inputs: Vec::new(),
+                    });
+                    modules.len() - 1
+                });
+                modules[from].outputs.push(to);
+                modules[to].inputs.push(from);
+            }
+        }
+
+        let slots = modules
+            .iter()
+            .enumerate()
+            .map(|(id, module)| {
+                module
+                    .outputs
+                    .iter()
+                    .map(|to| modules[*to].inputs.iter().position(|i| *i == id).unwrap())
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self {
+            modules,
+            ids,
+            slots,
+        })
+    }
+}
+
+impl Circuit {
+    pub fn id(&self, name: &str) -> Option<ModuleId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn module(&self, id: ModuleId) -> &Module {
+        &self.modules[id]
+    }
+
+    pub fn modules(&self) -> &[Module] {
+        &self.modules
+    }
+
+    pub fn button(&self) -> ModuleId {
+        0
+    }
+}
+
+// Flip-flop and conjunction memory, every module starts off with all inputs remembered low.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct State {
+    on: Vec<bool>,
+    memory: Vec<Vec<Pulse>>,
+}
+
+impl State {
+    pub fn new(circuit: &Circuit) -> Self {
+        Self {
+            on: vec![false; circuit.modules.len()],
+            memory: circuit
+                .modules
+                .iter()
+                .map(|m| match m.kind {
+                    Kind::Conjunction => vec![Pulse::Low; m.inputs.len()],
+                    _ => Vec::new(),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn is_on(&self, id: ModuleId) -> bool {
+        self.on[id]
+    }
+
+    // Last pulse from every input, ordered like `Module::inputs`.
+    pub fn memory(&self, id: ModuleId) -> &[Pulse] {
+        &self.memory[id]
+    }
+
+    // Only the memory of `ids`, in that order.
+    pub fn restricted_to(&self, ids: &[ModuleId]) -> State {
+        Self {
+            on: ids.iter().map(|id| self.on[*id]).collect(),
+            memory: ids.iter().map(|id| self.memory[*id].clone()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Snapshot {
+    pub state: State,
+    pub presses: usize,
+}
+
+pub trait Observer {
+    fn observe(&mut self, press: usize, event: &Event);
+}
+
+impl<F: FnMut(usize, &Event)> Observer for F {
+    fn observe(&mut self, press: usize, event: &Event) {
+        self(press, event)
+    }
+}
+
+impl<O: Observer> Observer for [O] {
+    fn observe(&mut self, press: usize, event: &Event) {
+        for observer in self.iter_mut() {
+            observer.observe(press, event);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PulseCounter {
+    pub low: usize,
+    pub high: usize,
+}
+
+impl Observer for PulseCounter {
+    fn observe(&mut self, _press: usize, event: &Event) {
+        match event.pulse {
+            Pulse::Low => self.low += 1,
+            Pulse::High => self.high += 1,
+        }
+    }
+}
+
+// Records the presses on which `from` sends `pulse` to `to`, once per press.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EdgeWatcher {
+    pub from: ModuleId,
+    pub to: ModuleId,
+    pub pulse: Pulse,
+    pub presses: Vec<usize>,
+}
+
+impl EdgeWatcher {
+    pub fn new(from: ModuleId, to: ModuleId, pulse: Pulse) -> Self {
+        Self {
+            from,
+            to,
+            pulse,
+            presses: Vec::new(),
+        }
+    }
+}
+
+impl Observer for EdgeWatcher {
+    fn observe(&mut self, press: usize, event: &Event) {
+        if event.from == self.from
+            && event.to == self.to
+            && event.pulse == self.pulse
+            && self.presses.last() != Some(&press)
+        {
+            self.presses.push(press);
+        }
+    }
+}
+
+pub struct Simulator<'a> {
+    circuit: &'a Circuit,
+    state: State,
+    presses: usize,
+    queue: VecDeque<(Event, usize)>,
+}
+
+impl<'a> Simulator<'a> {
+    pub fn new(circuit: &'a Circuit) -> Self {
+        Self {
+            circuit,
+            state: State::new(circuit),
+            presses: 0,
+            queue: VecDeque::new(),
+        }
+    }
+
+    pub fn circuit(&self) -> &'a Circuit {
+        self.circuit
+    }
+
+    pub fn presses(&self) -> usize {
+        self.presses
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            state: self.state.clone(),
+            presses: self.presses,
+        }
+    }
+
+    pub fn restore(&mut self, snapshot: Snapshot) {
+        self.state = snapshot.state;
+        self.presses = snapshot.presses;
+    }
+
+    // Every pulse sent because of one button press, in the order they are handled.
+    pub fn press_button(&mut self) -> Vec<Event> {
+        let mut log = Vec::new();
+        self.press_button_with(&mut |_, event: &Event| log.push(*event));
+        log
+    }
+
+    pub fn press_button_with<O: Observer + ?Sized>(&mut self, observer: &mut O) {
+        self.presses += 1;
+        self.send(self.circuit.button(), Pulse::Low);
+
+        while let Some((event, slot)) = self.queue.pop_front() {
+            observer.observe(self.presses, &event);
+
+            let module = &self.circuit.modules[event.to];
+            let pulse = match module.kind {
+                Kind::Button | Kind::Output => continue,
+                Kind::Broadcast => event.pulse,
+                Kind::FlipFlop => {
+                    if event.pulse == Pulse::High {
+                        continue;
+                    }
+                    let on = &mut self.state.on[event.to];
+                    *on = !*on;
+                    if *on {
+                        Pulse::High
+                    } else {
+                        Pulse::Low
+                    }
+                }
+                Kind::Conjunction => {
+                    let memory = &mut self.state.memory[event.to];
+                    memory[slot] = event.pulse;
+                    if memory.iter().all(|p| *p == Pulse::High) {
+                        Pulse::Low
+                    } else {
+                        Pulse::High
+                    }
+                }
+            };
+            self.send(event.to, pulse);
+        }
+    }
+
+    fn send(&mut self, from: ModuleId, pulse: Pulse) {
+        let outputs = self.circuit.modules[from].outputs.iter();
+        for (to, slot) in outputs.zip(self.circuit.slots[from].iter()) {
+            self.queue.push_back((
+                Event {
+                    from,
+                    to: *to,
+                    pulse,
+                },
+                *slot,
+            ));
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use std::fs;
+
+use circuit::{Circuit, PulseCounter, Simulator};
+use periods::first_low_pulse;
+use utils::solution::Solution;
+
+pub mod circuit;
+pub mod periods;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Circuit;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        fs::read_to_string(input_file)
+            .unwrap()
+            .parse()
+            .unwrap_or_else(|e| panic!("Could not parse {}: {}", input_file, e))
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn run(circuit: &Circuit) -> usize {
+    // Preamble
+    const PRESSES: usize = 1000;
+    let mut simulator = Simulator::new(circuit);
+    let mut counter = PulseCounter::default();
+
+    // Solve
+    for _ in 0..PRESSES {
+        simulator.press_button_with(&mut counter);
+    }
+
+    // Result
+    counter.low * counter.high
+}
+
+fn run2(circuit: &Circuit) -> usize {
+    // Preamble
+    const MAX_PRESSES: usize = 1 << 20;
+
+    // Solve
+    // Result
+    first_low_pulse(circuit, "rx", MAX_PRESSES).unwrap_or_else(|e| panic!("{}", e))
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+    use utils::get_test_input_path;
+    use utils::solution::Solution;
+
+    use crate::circuit::{Circuit, EdgeWatcher, Event, Pulse, Simulator};
+    use crate::periods::{first_low_pulse, PeriodError};
+    use crate::Day20;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day20>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_2_part_1() {
+        check_answer::<Day20>(file!(), "input_test_2", 1);
+    }
+
+    #[test]
+    fn test_press_button() {
+        let input_path = get_test_input_path(file!());
+        let circuit = Day20::parse(input_path.to_str().unwrap());
+        let inv = circuit.id("inv").unwrap();
+        let a = circuit.id("a").unwrap();
+
+        let mut simulator = Simulator::new(&circuit);
+        let initial = simulator.snapshot();
+        let log = simulator.press_button();
+        assert_eq!(log.len(), 12);
+        assert_eq!(log.iter().filter(|e| e.pulse == Pulse::High).count(), 4);
+        assert_eq!(
+            log.last(),
+            Some(&Event {
+                from: inv,
+                to: a,
+                pulse: Pulse::High
+            })
+        );
+        // Every flip-flop is back off after a single press.
+        assert_eq!(simulator.state(), &initial.state);
+
+        let mut watcher = EdgeWatcher::new(inv, a, Pulse::Low);
+        simulator.press_button_with(&mut watcher);
+        assert_eq!(watcher.presses, vec![2]);
+    }
+
+    #[test]
+    fn test_snapshot_restore() {
+        let circuit: Circuit =
+            "broadcaster -> a\n%a -> inv, con\n&inv -> b\n%b -> con\n&con -> output"
+                .parse()
+                .unwrap();
+        let mut simulator = Simulator::new(&circuit);
+        simulator.press_button();
+        let snapshot = simulator.snapshot();
+        let log = simulator.press_button();
+
+        simulator.press_button();
+        simulator.restore(snapshot.clone());
+        assert_eq!(simulator.snapshot(), snapshot);
+        assert_eq!(simulator.press_button(), log);
+        assert_eq!(simulator.presses(), 2);
+    }
+
+    // A binary counter like the ones in the real input, `name` fires every `period` presses.
+    fn counter(name: &str, period: usize) -> String {
+        let bits = usize::BITS - period.leading_zeros();
+        let mut lines = Vec::new();
+        let mut reset = vec![format!("{}0", name), format!("i{}", name)];
+        for bit in 0..bits {
+            let mut outputs = Vec::new();
+            if bit + 1 < bits {
+                outputs.push(format!("{}{}", name, bit + 1));
+            }
+            if period & (1 << bit) != 0 {
+                outputs.push(format!("c{}", name));
+            } else {
+                reset.push(format!("{}{}", name, bit));
+            }
+            lines.push(format!("%{}{} -> {}", name, bit, outputs.join(", ")));
+        }
+        lines.push(format!("&c{} -> {}", name, reset.join(", ")));
+        lines.push(format!("&i{} -> hub", name));
+        lines.join("\n")
+    }
+
+    fn brute_force(circuit: &Circuit) -> usize {
+        let hub = circuit.id("hub").unwrap();
+        let rx = circuit.id("rx").unwrap();
+        let mut watcher = EdgeWatcher::new(hub, rx, Pulse::Low);
+        let mut simulator = Simulator::new(circuit);
+        while watcher.presses.is_empty() {
+            simulator.press_button_with(&mut watcher);
+        }
+        watcher.presses[0]
+    }
+
+    #[test]
+    fn test_first_low_pulse() {
+        for periods in [vec![5, 7], vec![9, 6, 15], vec![11]] {
+            let names: Vec<String> = (0..periods.len()).map(|i| format!("k{}", i)).collect();
+            let mut content = format!(
+                "broadcaster -> {}\n&hub -> rx\n",
+                names
+                    .iter()
+                    .map(|n| format!("{}0", n))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            for (name, period) in names.iter().zip(periods.iter()) {
+                content.push_str(&counter(name, *period));
+                content.push('\n');
+            }
+            let circuit: Circuit = content.parse().unwrap();
+
+            assert_eq!(
+                first_low_pulse(&circuit, "rx", 1000),
+                Ok(brute_force(&circuit)),
+                "{:?}",
+                periods
+            );
+        }
+    }
+
+    #[test]
+    fn test_first_low_pulse_errors() {
+        let input_path = get_test_input_path(file!());
+        let circuit = Day20::parse(input_path.to_str().unwrap());
+        assert_eq!(
+            first_low_pulse(&circuit, "rx", 1000),
+            Err(PeriodError::UnknownOutput("rx".into()))
+        );
+        assert!(matches!(
+            first_low_pulse(&circuit, "inv", 1000),
+            Err(PeriodError::NotDecomposable(_))
+        ));
+    }
+}
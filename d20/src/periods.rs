@@ -0,0 +1,292 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt,
+};
+
+use utils::number::crt;
+
+use crate::circuit::{Circuit, EdgeWatcher, Kind, ModuleId, Pulse, Simulator, State, BROADCASTER};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PeriodError {
+    UnknownOutput(String),
+    // The circuit does not have the shape the period finding relies on.
+    NotDecomposable(String),
+    NoPeriod { entry: String, presses: usize },
+    Never,
+}
+
+impl fmt::Display for PeriodError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PeriodError::UnknownOutput(name) => write!(f, "no module named {}", name),
+            PeriodError::NotDecomposable(reason) => {
+                write!(f, "circuit can not be split into sub-circuits: {}", reason)
+            }
+            PeriodError::NoPeriod { entry, presses } => write!(
+                f,
+                "sub-circuit starting at {} does not repeat within {} presses",
+                entry, presses
+            ),
+            PeriodError::Never => write!(f, "the sub-circuits never fire on the same press"),
+        }
+    }
+}
+
+impl std::error::Error for PeriodError {}
+
+// Modules behind one broadcaster output, only connected to the rest through `exit` -> hub.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubCircuit {
+    pub entry: ModuleId,
+    pub exit: ModuleId,
+    pub modules: Vec<ModuleId>,
+}
+
+// The broadcaster outputs, each with its own sub-circuit, feed one conjunction `hub` which is the
+// only input of `target`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decomposition {
+    pub target: ModuleId,
+    pub hub: ModuleId,
+    pub sub_circuits: Vec<SubCircuit>,
+}
+
+impl Decomposition {
+    pub fn new(circuit: &Circuit, target: &str) -> Result<Self, PeriodError> {
+        let not_decomposable = |reason: String| Err(PeriodError::NotDecomposable(reason));
+        let name = |id: ModuleId| circuit.module(id).name.as_str();
+
+        let target = circuit
+            .id(target)
+            .ok_or_else(|| PeriodError::UnknownOutput(target.to_string()))?;
+        let hub = match circuit.module(target).inputs[..] {
+            [hub] if circuit.module(hub).kind == Kind::Conjunction => hub,
+            _ => {
+                return not_decomposable(format!(
+                    "{} is not fed by a single conjunction",
+                    name(target)
+                ))
+            }
+        };
+        if circuit.module(hub).outputs != [target] {
+            return not_decomposable(format!(
+                "{} has outputs besides {}",
+                name(hub),
+                name(target)
+            ));
+        }
+
+        let broadcaster = circuit.id(BROADCASTER).unwrap();
+        let mut owner: HashMap<ModuleId, usize> = HashMap::new();
+        let mut sub_circuits = Vec::new();
+
+        for &entry in circuit.module(broadcaster).outputs.iter() {
+            let mut modules = vec![entry];
+            let mut seen = HashSet::from([entry]);
+            let mut queue = VecDeque::from([entry]);
+            let mut exits = Vec::new();
+
+            while let Some(id) = queue.pop_front() {
+                for &next in circuit.module(id).outputs.iter() {
+                    if next == hub {
+                        exits.push(id);
+                    } else if seen.insert(next) {
+                        modules.push(next);
+                        queue.push_back(next);
+                    }
+                }
+            }
+
+            let exit = match exits[..] {
+                [exit] => exit,
+                _ => {
+                    return not_decomposable(format!(
+                        "{} reaches {} through {} modules instead of one",
+                        name(entry),
+                        name(hub),
+                        exits.len()
+                    ))
+                }
+            };
+            for &id in modules.iter() {
+                if id == target || owner.insert(id, sub_circuits.len()).is_some() {
+                    return not_decomposable(format!(
+                        "{} is reachable from more than one broadcaster output",
+                        name(id)
+                    ));
+                }
+            }
+            sub_circuits.push(SubCircuit {
+                entry,
+                exit,
+                modules,
+            });
+        }
+
+        // Nothing but the broadcaster and the sub-circuit itself may send pulses into it.
+        for (index, sub_circuit) in sub_circuits.iter().enumerate() {
+            for &id in sub_circuit.modules.iter() {
+                let outside = circuit
+                    .module(id)
+                    .inputs
+                    .iter()
+                    .find(|i| **i != broadcaster && owner.get(i) != Some(&index));
+                if let Some(&outside) = outside {
+                    return not_decomposable(format!(
+                        "{} gets pulses from {} outside its sub-circuit",
+                        name(id),
+                        name(outside)
+                    ));
+                }
+            }
+        }
+        if circuit.module(hub).inputs.len() != sub_circuits.len() {
+            return not_decomposable(format!("{} has inputs outside the sub-circuits", name(hub)));
+        }
+
+        Ok(Self {
+            target,
+            hub,
+            sub_circuits,
+        })
+    }
+}
+
+// When a sub-circuit's exit sends a high pulse to the hub. Presses are counted from 1, the
+// pattern repeats every `period` presses after the first `prefix` ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Period {
+    pub prefix: usize,
+    pub period: usize,
+    // Presses up to `prefix + period` on which the exit fires.
+    pub hits: Vec<usize>,
+}
+
+impl Period {
+    pub fn fires_on(&self, press: usize) -> bool {
+        let press = if press > self.prefix + self.period {
+            self.prefix + 1 + (press - self.prefix - 1) % self.period
+        } else {
+            press
+        };
+        self.hits.contains(&press)
+    }
+}
+
+// Simulates the whole circuit until every sub-circuit's state repeats. Exits must send their
+// high pulse and the low pulse that resets the hub's memory during the same press, so the hub
+// only sends a low pulse on presses on which every exit fires.
+pub fn find_periods(
+    circuit: &Circuit,
+    decomposition: &Decomposition,
+    max_presses: usize,
+) -> Result<Vec<Period>, PeriodError> {
+    let hub = circuit.module(decomposition.hub);
+    let mut watchers: Vec<EdgeWatcher> = decomposition
+        .sub_circuits
+        .iter()
+        .map(|s| EdgeWatcher::new(s.exit, decomposition.hub, Pulse::High))
+        .collect();
+    let mut seen: Vec<HashMap<State, usize>> = vec![HashMap::new(); watchers.len()];
+    let mut periods: Vec<Option<Period>> = vec![None; watchers.len()];
+
+    let mut simulator = Simulator::new(circuit);
+    for (index, sub_circuit) in decomposition.sub_circuits.iter().enumerate() {
+        seen[index].insert(simulator.state().restricted_to(&sub_circuit.modules), 0);
+    }
+
+    while periods.iter().any(|p| p.is_none()) {
+        if simulator.presses() == max_presses {
+            let index = periods.iter().position(|p| p.is_none()).unwrap();
+            return Err(PeriodError::NoPeriod {
+                entry: circuit
+                    .module(decomposition.sub_circuits[index].entry)
+                    .name
+                    .clone(),
+                presses: max_presses,
+            });
+        }
+        simulator.press_button_with(watchers.as_mut_slice());
+        let press = simulator.presses();
+
+        for (index, sub_circuit) in decomposition.sub_circuits.iter().enumerate() {
+            let slot = hub
+                .inputs
+                .iter()
+                .position(|i| *i == sub_circuit.exit)
+                .unwrap();
+            if simulator.state().memory(decomposition.hub)[slot] == Pulse::High {
+                return Err(PeriodError::NotDecomposable(format!(
+                    "{} stays high after press {}",
+                    circuit.module(sub_circuit.exit).name,
+                    press
+                )));
+            }
+            if periods[index].is_some() {
+                continue;
+            }
+
+            let state = simulator.state().restricted_to(&sub_circuit.modules);
+            if let Some(&prefix) = seen[index].get(&state) {
+                periods[index] = Some(Period {
+                    prefix,
+                    period: press - prefix,
+                    hits: watchers[index].presses.clone(),
+                });
+            } else {
+                seen[index].insert(state, press);
+            }
+        }
+    }
+
+    Ok(periods.into_iter().map(|p| p.unwrap()).collect())
+}
+
+// First press on which `target` receives a low pulse.
+pub fn first_low_pulse(
+    circuit: &Circuit,
+    target: &str,
+    max_presses: usize,
+) -> Result<usize, PeriodError> {
+    let decomposition = Decomposition::new(circuit, target)?;
+    let periods = find_periods(circuit, &decomposition, max_presses)?;
+    let start = periods.iter().map(|p| p.prefix).max().unwrap_or(0) + 1;
+
+    // Before every sub-circuit is inside its cycle, just check each press.
+    if let Some(press) = (1..start).find(|n| periods.iter().all(|p| p.fires_on(*n))) {
+        return Ok(press);
+    }
+
+    // Afterwards every combination of cyclic hits is a system of congruences.
+    let mut combinations: Vec<Vec<(i128, i128)>> = vec![Vec::new()];
+    for period in periods.iter() {
+        let residues: Vec<(i128, i128)> = period
+            .hits
+            .iter()
+            .filter(|h| **h > period.prefix)
+            .map(|h| (*h as i128, period.period as i128))
+            .collect();
+        combinations = combinations
+            .iter()
+            .flat_map(|c| {
+                residues.iter().map(move |r| {
+                    let mut c = c.clone();
+                    c.push(*r);
+                    c
+                })
+            })
+            .collect();
+    }
+
+    combinations
+        .iter()
+        .filter_map(|c| crt(c))
+        .map(|(residue, modulus)| {
+            let behind = (start as i128 - residue).max(0) as u128;
+            let steps = behind.div_ceil(modulus as u128) as i128;
+            (residue + steps * modulus) as usize
+        })
+        .min()
+        .ok_or(PeriodError::Never)
+}
@@ -0,0 +1,194 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+use utils::{
+    hash_point_map::HashPointMap, point::MapPoint, search::bfs_distances, solution::Solution,
+};
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Garden;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse(input_file)
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+#[derive(Debug)]
+struct Walk {
+    steps: Vec<usize>,
+    map: HashPointMap<char>,
+    max_x: i64,
+    max_y: i64,
+}
+impl Walk {
+    fn go(&self, start: MapPoint) -> Vec<usize> {
+        let max_steps = *self.steps.iter().max().unwrap();
+
+        let distances = bfs_distances(
+            [start],
+            |point: &MapPoint| {
+                point
+                    .generate_non_diagonal_neigbors()
+                    .into_iter()
+                    .filter(|neighbor| {
+                        let adjusted_neighbor = MapPoint::new(
+                            neighbor.x.rem_euclid(self.max_x),
+                            neighbor.y.rem_euclid(self.max_y),
+                        );
+                        !self.map.contains(&adjusted_neighbor)
+                    })
+            },
+            Some(max_steps),
+        );
+
+        self.steps
+            .iter()
+            .map(|steps| {
+                distances
+                    .values()
+                    .filter(|dist| *dist <= steps && *dist % 2 == steps % 2)
+                    .count()
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct Garden {
+    map: HashPointMap<char>,
+    start_point: MapPoint,
+    max_x: usize,
+    max_y: usize,
+}
+
+fn parse(input_file: &str) -> Garden {
+    // Preamble
+    let mut map: HashPointMap<char> = HashPointMap::default();
+
+    // Parse
+    let file = File::open(input_file).unwrap();
+    let reader = BufReader::new(file);
+
+    let mut start_point: MapPoint = MapPoint::default();
+    let mut max_x = 0;
+    let mut max_y = 0;
+
+    for (y, line) in reader.lines().enumerate() {
+        let line = line.unwrap().trim().to_string();
+
+        max_y = max_y.max(y);
+
+        for (x, c) in line.chars().enumerate() {
+            max_x = max_x.max(x);
+            match c {
+                '#' => {
+                    map.insert(MapPoint::new(x as i64, y as i64), '#');
+                }
+                'S' => {
+                    start_point = MapPoint::new(x as i64, y as i64);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Garden {
+        map,
+        start_point,
+        max_x,
+        max_y,
+    }
+}
+
+fn run(garden: &Garden) -> usize {
+    // Preamble
+    #[cfg(test)]
+    const STEPS: usize = 6;
+
+    #[cfg(not(test))]
+    const STEPS: usize = 64;
+
+    let walk = Walk {
+        steps: vec![STEPS],
+        map: garden.map.clone(),
+        max_x: garden.max_x as i64 + 1,
+        max_y: garden.max_y as i64 + 1,
+    };
+
+    // Solve
+    let result = walk.go(garden.start_point);
+
+    // Result
+    *result.first().unwrap()
+}
+
+fn run2(garden: &Garden) -> usize {
+    // Preamble
+    // Grid is quadratic (SIDE x SIDE) (131 x 131)
+    // We start in the middle (65, 65)
+    // filled area increase will cycle with a period of SIDE
+    // So we can fit a 2nd degree polynomial f(n)
+    // Let n=0 be SIDE // 2 steps, n=1 is SIDE // 2 + SIDE
+    // n=3 is SIDE // 2 + 2*SIDE
+    // 26501365 - 65 happens to be divisible by 131
+    const STEPS: usize = 26501365;
+
+    let half = garden.start_point.x as usize;
+    let side = garden.max_x + 1;
+
+    let walk = Walk {
+        steps: vec![half, half + side, half + 2 * side],
+        map: garden.map.clone(),
+        max_x: garden.max_x as i64 + 1,
+        max_y: garden.max_y as i64 + 1,
+    };
+
+    // Solve
+    let result = walk.go(garden.start_point);
+
+    let f0 = result[0];
+    let f1 = result[1];
+    let f2 = result[2];
+
+    let c = f0;
+    let a = (f2 - 2 * f1 + f0) / 2;
+    let b = f1 - f0 - a;
+    let n = (STEPS - half) / side;
+
+    let final_result = a * n.pow(2) + b * n + c;
+
+    // Result 612941134797232
+    final_result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::Day21;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day21>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    #[ignore = "needs the real puzzle input and its part_2 answer in an [input] section"]
+    fn test_input_part_2() {
+        check_answer::<Day21>(file!(), "input", 2);
+    }
+}
@@ -0,0 +1,148 @@
+use std::fs;
+
+use stack::{Brick, ChainMethod, Stack};
+use utils::solution::Solution;
+
+pub mod stack;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Brick>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        fs::read_to_string(input_file)
+            .unwrap()
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.parse()
+                    .unwrap_or_else(|e| panic!("Could not parse {}: {}", input_file, e))
+            })
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn run(bricks: &[Brick]) -> usize {
+    // Preamble
+    let stack = Stack::settle(bricks);
+
+    // Solve
+    let result = stack.safe_count();
+
+    // Result
+    result
+}
+
+fn run2(bricks: &[Brick]) -> usize {
+    // Preamble
+    let stack = Stack::settle(bricks);
+
+    // Solve
+    let result = stack
+        .chain_reactions(ChainMethod::DominatorTree)
+        .iter()
+        .sum();
+
+    // Result
+    result
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+    use utils::get_test_input_path;
+    use utils::solution::Solution;
+
+    use crate::stack::{Brick, ChainMethod, Stack};
+    use crate::Day22;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day22>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day22>(file!(), "input_test", 2);
+    }
+
+    #[test]
+    fn test_settle() {
+        let input_path = get_test_input_path(file!());
+        let bricks = Day22::parse(input_path.to_str().unwrap());
+        let stack = Stack::settle(&bricks);
+
+        // D and E both rest on B and C, F on D and E and G on F.
+        let z: Vec<i64> = stack.bricks.iter().map(|b| b.start.z).collect();
+        assert_eq!(z, [1, 2, 2, 3, 3, 4, 5]);
+        assert_eq!(stack.supported_by[0], Vec::<usize>::new());
+        assert_eq!(stack.supports[0], [1, 2]);
+        assert_eq!(stack.supported_by[3], [1, 2]);
+        assert_eq!(stack.supported_by[6], [5]);
+
+        let safe: Vec<bool> = (0..stack.len())
+            .map(|id| stack.is_safe_to_disintegrate(id))
+            .collect();
+        assert_eq!(safe, [false, true, true, true, true, false, true]);
+        assert_eq!(stack.chain_reaction(0), 6);
+        assert_eq!(stack.chain_reaction(5), 1);
+        assert_eq!(
+            stack.dominator_tree(),
+            [None, Some(0), Some(0), Some(0), Some(0), Some(0), Some(5)]
+        );
+        assert_eq!(
+            stack.chain_reactions(ChainMethod::DominatorTree),
+            stack.chain_reactions(ChainMethod::Simulate)
+        );
+    }
+
+    #[test]
+    fn test_chain_reaction_methods() {
+        // Pseudo random towers on a small floor, with several bricks on the ground.
+        let mut seed = 7u64;
+        let mut random = |range: i64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as i64 % range
+        };
+        let bricks: Vec<Brick> = (0..300)
+            .map(|z| {
+                let (x, y) = (random(6), random(6));
+                let (dx, dy, dz) = match random(3) {
+                    0 => (random(3), 0, 0),
+                    1 => (0, random(3), 0),
+                    _ => (0, 0, random(3)),
+                };
+                format!("{},{},{}~{},{},{}", x, y, z + 1, x + dx, y + dy, z + 1 + dz)
+                    .parse()
+                    .unwrap()
+            })
+            .collect();
+        let stack = Stack::settle(&bricks);
+        let simulated = stack.chain_reactions(ChainMethod::Simulate);
+        assert_eq!(stack.chain_reactions(ChainMethod::DominatorTree), simulated);
+        assert!(simulated.iter().any(|c| *c > 10));
+    }
+
+    #[test]
+    fn test_brick_parse() {
+        assert!("1,2~3,4,5".parse::<Brick>().is_err());
+        assert!("1,2,0~3,4,5".parse::<Brick>().is_err());
+        assert_eq!(
+            "3,4,5~1,2,7".parse::<Brick>().unwrap().start,
+            "1,2,5~3,4,7".parse::<Brick>().unwrap().start
+        );
+    }
+}
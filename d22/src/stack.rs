@@ -0,0 +1,242 @@
+use std::str::FromStr;
+
+use utils::{grid::Grid, vector3::Vector3};
+
+// Both corners are inclusive, `start` holds the smaller coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Brick {
+    pub start: Vector3,
+    pub end: Vector3,
+}
+
+impl FromStr for Brick {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let corner = |part: &str| -> Result<Vector3, String> {
+            let values: Vec<i64> = part
+                .split(',')
+                .map(|v| {
+                    v.trim()
+                        .parse()
+                        .map_err(|_| format!("invalid coordinate \"{}\"", v))
+                })
+                .collect::<Result<_, _>>()?;
+            let [x, y, z] = values[..] else {
+                return Err(format!("expected x,y,z in \"{}\"", part));
+            };
+            if x < 0 || y < 0 || z < 1 {
+                return Err(format!("\"{}\" is below the ground or off the grid", part));
+            }
+            Ok(Vector3::new(x, y, z))
+        };
+
+        let (a, b) = s
+            .split_once('~')
+            .ok_or_else(|| format!("expected \"start~end\" in \"{}\"", s))?;
+        let (a, b) = (corner(a)?, corner(b)?);
+        Ok(Self {
+            start: Vector3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            end: Vector3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        })
+    }
+}
+
+impl Brick {
+    pub fn height(&self) -> i64 {
+        self.end.z - self.start.z + 1
+    }
+
+    fn footprint(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (self.start.y..=self.end.y)
+            .flat_map(|y| (self.start.x..=self.end.x).map(move |x| (x as usize, y as usize)))
+    }
+
+    fn dropped_to(&self, z: i64) -> Self {
+        Self {
+            start: Vector3::new(self.start.x, self.start.y, z),
+            end: Vector3::new(self.end.x, self.end.y, z + self.height() - 1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainMethod {
+    // Drops everything above every brick again, quadratic in the worst case.
+    Simulate,
+    // Counts the bricks each brick dominates in the support DAG.
+    DominatorTree,
+}
+
+// Settled bricks, numbered from the lowest up so that supporters always come first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stack {
+    pub bricks: Vec<Brick>,
+    // Bricks resting directly on each brick.
+    pub supports: Vec<Vec<usize>>,
+    // Bricks each brick rests on directly, empty for the ground.
+    pub supported_by: Vec<Vec<usize>>,
+}
+
+impl Stack {
+    pub fn settle(bricks: &[Brick]) -> Self {
+        let mut falling = bricks.to_vec();
+        falling.sort_unstable_by_key(|b| b.start.z);
+
+        let width = bricks.iter().map(|b| b.end.x + 1).max().unwrap_or(0) as usize;
+        let depth = bricks.iter().map(|b| b.end.y + 1).max().unwrap_or(0) as usize;
+        // Height of the highest cube in every column and the brick it belongs to.
+        let mut tops: Grid<(i64, Option<usize>)> = Grid::with_width_height(width, depth, (0, None));
+
+        let mut rtn = Self {
+            bricks: Vec::with_capacity(falling.len()),
+            supports: vec![Vec::new(); falling.len()],
+            supported_by: vec![Vec::new(); falling.len()],
+        };
+
+        for (id, brick) in falling.iter().enumerate() {
+            let rest = brick
+                .footprint()
+                .map(|(x, y)| tops.get(x, y).unwrap().0)
+                .max()
+                .unwrap_or(0);
+
+            let mut below: Vec<usize> = brick
+                .footprint()
+                .filter_map(|(x, y)| match *tops.get(x, y).unwrap() {
+                    (height, Some(top)) if height == rest => Some(top),
+                    _ => None,
+                })
+                .collect();
+            below.sort_unstable();
+            below.dedup();
+            for other in below.iter() {
+                rtn.supports[*other].push(id);
+            }
+            rtn.supported_by[id] = below;
+
+            let settled = brick.dropped_to(rest + 1);
+            for (x, y) in settled.footprint() {
+                tops.set(x, y, (settled.end.z, Some(id)));
+            }
+            rtn.bricks.push(settled);
+        }
+
+        rtn
+    }
+
+    pub fn len(&self) -> usize {
+        self.bricks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bricks.is_empty()
+    }
+
+    // Nothing falls without `id`, every brick on it has another support.
+    pub fn is_safe_to_disintegrate(&self, id: usize) -> bool {
+        self.supports[id]
+            .iter()
+            .all(|above| self.supported_by[*above].len() > 1)
+    }
+
+    pub fn safe_count(&self) -> usize {
+        (0..self.len())
+            .filter(|id| self.is_safe_to_disintegrate(*id))
+            .count()
+    }
+
+    // Number of other bricks that fall when `id` is disintegrated.
+    pub fn chain_reaction(&self, id: usize) -> usize {
+        let mut remaining: Vec<usize> = self.supported_by.iter().map(|s| s.len()).collect();
+        let mut falling = vec![id];
+        let mut rtn = 0;
+
+        while let Some(brick) = falling.pop() {
+            for above in self.supports[brick].iter() {
+                remaining[*above] -= 1;
+                if remaining[*above] == 0 {
+                    rtn += 1;
+                    falling.push(*above);
+                }
+            }
+        }
+
+        rtn
+    }
+
+    pub fn chain_reactions(&self, method: ChainMethod) -> Vec<usize> {
+        match method {
+            ChainMethod::Simulate => (0..self.len()).map(|id| self.chain_reaction(id)).collect(),
+            ChainMethod::DominatorTree => {
+                // A brick falls exactly with the bricks that dominate it.
+                let mut sizes = vec![1; self.len()];
+                let dominators = self.dominator_tree();
+                for id in (0..self.len()).rev() {
+                    if let Some(parent) = dominators[id] {
+                        sizes[parent] += sizes[id];
+                    }
+                }
+                sizes.iter().map(|s| s - 1).collect()
+            }
+        }
+    }
+
+    // Immediate dominator of every brick with the ground as root, None if only the ground
+    // dominates it. Removing a brick drops exactly the bricks in its subtree.
+    pub fn dominator_tree(&self) -> Vec<Option<usize>> {
+        // Bricks come in topological order, so the dominator of a brick is the lowest common
+        // ancestor of its supports in the tree built so far. Ancestors are kept for binary lifting,
+        // `ancestors[id][k]` is the 2^k-th one.
+        let mut rtn: Vec<Option<usize>> = Vec::with_capacity(self.len());
+        let mut depth: Vec<usize> = Vec::with_capacity(self.len());
+        let mut ancestors: Vec<Vec<usize>> = Vec::with_capacity(self.len());
+
+        let lca = |mut a: usize, mut b: usize, depth: &[usize], ancestors: &[Vec<usize>]| {
+            if depth[a] < depth[b] {
+                std::mem::swap(&mut a, &mut b);
+            }
+            for k in (0..ancestors[a].len()).rev() {
+                if let Some(&up) = ancestors[a].get(k) {
+                    if depth[up] >= depth[b] {
+                        a = up;
+                    }
+                }
+            }
+            if a == b {
+                return Some(a);
+            }
+            for k in (0..ancestors[a].len().max(ancestors[b].len())).rev() {
+                if let (Some(&up_a), Some(&up_b)) = (ancestors[a].get(k), ancestors[b].get(k)) {
+                    if up_a != up_b {
+                        a = up_a;
+                        b = up_b;
+                    }
+                }
+            }
+            // Different trees below the ground.
+            let (up_a, up_b) = (ancestors[a].first(), ancestors[b].first());
+            (up_a.is_some() && up_a == up_b).then(|| *up_a.unwrap())
+        };
+
+        for id in 0..self.len() {
+            let mut supports = self.supported_by[id].iter().copied();
+            let dominator = supports.next().and_then(|first| {
+                supports.try_fold(first, |acc, other| lca(acc, other, &depth, &ancestors))
+            });
+
+            let mut up = Vec::new();
+            if let Some(parent) = dominator {
+                up.push(parent);
+                while let Some(next) = ancestors[up[up.len() - 1]].get(up.len() - 1) {
+                    up.push(*next);
+                }
+            }
+            depth.push(dominator.map_or(0, |p| depth[p] + 1));
+            ancestors.push(up);
+            rtn.push(dominator);
+        }
+
+        rtn
+    }
+}
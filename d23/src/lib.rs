@@ -0,0 +1,165 @@
+use std::thread;
+
+use trail::{Slopes, TrailMap};
+use utils::{
+    grid::Grid, longest_path::SearchOptions, parse_into_char_vector_vector, solution::Solution,
+};
+
+pub mod trail;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse_into_char_vector_vector(input_file)
+            .try_into()
+            .unwrap()
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn hike(grid: &Grid<char>, slopes: Slopes) -> usize {
+    // Preamble
+    let trail_map =
+        TrailMap::new(grid, slopes).unwrap_or_else(|e| panic!("Invalid hiking map: {}", e));
+    let options = SearchOptions {
+        threads: thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1),
+        ..Default::default()
+    };
+
+    // Solve
+    let result = trail_map.longest_hike(&options);
+
+    // Result
+    result.expect("No way to the goal")
+}
+
+fn run(grid: &Grid<char>) -> usize {
+    hike(grid, Slopes::Respect)
+}
+
+fn run2(grid: &Grid<char>) -> usize {
+    hike(grid, Slopes::Ignore)
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+    use utils::get_test_input_path;
+    use utils::grid::Grid;
+    use utils::grid_point::GridPoint;
+    use utils::longest_path::SearchOptions;
+    use utils::solution::Solution;
+
+    use crate::trail::{Slopes, TrailError, TrailMap};
+    use crate::Day23;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day23>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day23>(file!(), "input_test", 2);
+    }
+
+    #[test]
+    fn test_trail_map() {
+        let input_path = get_test_input_path(file!());
+        let grid = Day23::parse(input_path.to_str().unwrap());
+
+        let slippery = TrailMap::new(&grid, Slopes::Respect).unwrap();
+        let dry = TrailMap::new(&grid, Slopes::Ignore).unwrap();
+        assert_eq!(slippery.junctions, dry.junctions);
+        assert_eq!(slippery.junctions.len(), 9);
+        assert_eq!(slippery.junctions[slippery.start], GridPoint::new(1, 0));
+
+        // The first corridor leads downhill into the junction at 3,5.
+        let junction = slippery.id(&GridPoint::new(3, 5)).unwrap();
+        let neighbours = |trail_map: &TrailMap, id: usize| -> Vec<(usize, usize)> {
+            trail_map
+                .graph
+                .neighbours(id)
+                .map(|(next, steps)| (next, *steps))
+                .collect()
+        };
+        assert_eq!(neighbours(&slippery, slippery.start), [(junction, 15)]);
+        assert!(neighbours(&slippery, junction)
+            .iter()
+            .all(|e| e.0 != slippery.start));
+        assert!(neighbours(&dry, junction).contains(&(dry.start, 15)));
+
+        let options = SearchOptions::default();
+        assert_eq!(slippery.longest_hike(&options), Some(94));
+        assert_eq!(dry.longest_hike(&options), Some(154));
+
+        let dot = slippery.to_dot();
+        assert!(dot.starts_with("digraph trails {"));
+        assert!(dot.contains(&format!("    0 -> {} [label=\"15\"];", junction)));
+        let dot = dry.to_dot();
+        assert!(dot.starts_with("graph trails {"));
+        assert_eq!(dot.matches(" -- ").count(), 12);
+    }
+
+    #[test]
+    fn test_invalid_trail_maps() {
+        let grid = |rows: &[&str]| -> Grid<char> {
+            rows.iter()
+                .map(|r| r.chars().collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap()
+        };
+
+        let dead_end = grid(&["#.###", "#...#", "#.#.#", "#.###"]);
+        assert_eq!(
+            TrailMap::new(&dead_end, Slopes::Ignore),
+            Err(TrailError::DeadEnd(GridPoint::new(3, 2)))
+        );
+        // The left border branches at 0,2 instead of ending a corridor.
+        let border = grid(&["##.##", "...##", "...##", "...##", "##.##"]);
+        let trail_map = TrailMap::new(&border, Slopes::Ignore).unwrap();
+        assert!(trail_map.id(&GridPoint::new(0, 2)).is_some());
+        assert!(trail_map.longest_hike(&SearchOptions::default()).is_some());
+
+        let two_starts = grid(&["#..#", "#..#", "#.##"]);
+        assert_eq!(
+            TrailMap::new(&two_starts, Slopes::Ignore),
+            Err(TrailError::MissingStart)
+        );
+        let empty: Grid<char> = Vec::<Vec<char>>::new().try_into().unwrap();
+        assert_eq!(
+            TrailMap::new(&empty, Slopes::Ignore),
+            Err(TrailError::Empty)
+        );
+        let unknown = grid(&["#.#", "#x#", "#.#"]);
+        assert_eq!(
+            TrailMap::new(&unknown, Slopes::Ignore),
+            Err(TrailError::UnknownTile {
+                position: GridPoint::new(1, 1),
+                tile: 'x'
+            })
+        );
+
+        // A slope against the way down leaves no hike at all.
+        let uphill = grid(&["#.#", "#^#", "#.#"]);
+        let trail_map = TrailMap::new(&uphill, Slopes::Respect).unwrap();
+        assert_eq!(trail_map.longest_hike(&SearchOptions::default()), None);
+        let trail_map = TrailMap::new(&uphill, Slopes::Ignore).unwrap();
+        assert_eq!(trail_map.longest_hike(&SearchOptions::default()), Some(2));
+    }
+}
@@ -0,0 +1,224 @@
+use std::{collections::HashMap, fmt, fmt::Write};
+
+use utils::{
+    graph::{EdgeKind, Graph},
+    grid::Grid,
+    grid_point::GridPoint,
+    longest_path::{longest_path, SearchOptions},
+    search::bfs_distances,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slopes {
+    // A slope can only be left downhill, the graph is directed.
+    Respect,
+    // Slopes are plain paths, every edge goes both ways.
+    Ignore,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrailError {
+    Empty,
+    UnknownTile { position: GridPoint, tile: char },
+    MissingStart,
+    MissingGoal,
+    // A corridor tile with a single way out that is neither start nor goal.
+    DeadEnd(GridPoint),
+}
+
+impl fmt::Display for TrailError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrailError::Empty => write!(f, "empty map"),
+            TrailError::UnknownTile { position, tile } => {
+                write!(f, "unknown tile {} at {},{}", tile, position.x, position.y)
+            }
+            TrailError::MissingStart => write!(f, "no single open tile in the top row"),
+            TrailError::MissingGoal => write!(f, "no single open tile in the bottom row"),
+            TrailError::DeadEnd(position) => {
+                write!(f, "corridor ends at {},{}", position.x, position.y)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TrailError {}
+
+// The hiking map compressed to its junctions, edges are weighted with the corridor length. Nodes
+// are named after the position of their junction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrailMap {
+    pub graph: Graph,
+    pub junctions: Vec<GridPoint>,
+    pub start: usize,
+    pub goal: usize,
+    pub slopes: Slopes,
+    // Index of every junction in `junctions`.
+    ids: HashMap<GridPoint, usize>,
+}
+
+fn slope_direction(tile: char) -> Option<(isize, isize)> {
+    match tile {
+        '>' => Some((1, 0)),
+        '<' => Some((-1, 0)),
+        'v' => Some((0, 1)),
+        '^' => Some((0, -1)),
+        _ => None,
+    }
+}
+
+impl TrailMap {
+    pub fn new(grid: &Grid<char>, slopes: Slopes) -> Result<Self, TrailError> {
+        if grid.get_max_x() == 0 || grid.get_max_y() == 0 {
+            return Err(TrailError::Empty);
+        }
+        for y in 0..grid.get_max_y() {
+            for x in 0..grid.get_max_x() {
+                let tile = *grid.get(x, y).unwrap();
+                if tile != '#' && tile != '.' && slope_direction(tile).is_none() {
+                    return Err(TrailError::UnknownTile {
+                        position: GridPoint::new(x, y),
+                        tile,
+                    });
+                }
+            }
+        }
+
+        let is_open = |p: &GridPoint| grid.get_from_point(p).is_some_and(|c| *c != '#');
+        let open_neighbours = |p: &GridPoint| -> Vec<GridPoint> {
+            p.generate_non_diagonal_neigbors()
+                .into_iter()
+                .filter(is_open)
+                .collect()
+        };
+        // Whether a single step from `from` onto the neighbour `to` is allowed.
+        let passable = |from: &GridPoint, to: &GridPoint| match slopes {
+            Slopes::Ignore => true,
+            Slopes::Respect => match slope_direction(*grid.get_from_point(from).unwrap()) {
+                None => true,
+                Some((dx, dy)) => {
+                    to.x as isize - from.x as isize == dx && to.y as isize - from.y as isize == dy
+                }
+            },
+        };
+        let single_open = |y: usize| -> Option<GridPoint> {
+            let open: Vec<GridPoint> = (0..grid.get_max_x())
+                .map(|x| GridPoint::new(x, y))
+                .filter(is_open)
+                .collect();
+            (open.len() == 1).then(|| open[0])
+        };
+
+        let start = single_open(0).ok_or(TrailError::MissingStart)?;
+        let goal = single_open(grid.get_max_y() - 1).ok_or(TrailError::MissingGoal)?;
+
+        // Open border tiles can branch as well, so the whole grid is searched.
+        let mut junctions = vec![start, goal];
+        for y in 0..grid.get_max_y() {
+            for x in 0..grid.get_max_x() {
+                let position = GridPoint::new(x, y);
+                if position == start || position == goal || !is_open(&position) {
+                    continue;
+                }
+                match open_neighbours(&position).len() {
+                    0 | 1 => return Err(TrailError::DeadEnd(position)),
+                    2 => {}
+                    _ => junctions.push(position),
+                }
+            }
+        }
+        let ids: HashMap<GridPoint, usize> =
+            junctions.iter().enumerate().map(|(i, p)| (*p, i)).collect();
+
+        // Every corridor is searched from both ends, each search adds the edge leaving its start.
+        // Without slopes both searches find the same edge, so only the one from the lower id is kept.
+        let mut graph = Graph::new(match slopes {
+            Slopes::Respect => EdgeKind::Directed,
+            Slopes::Ignore => EdgeKind::Undirected,
+        });
+        for junction in junctions.iter() {
+            graph.intern(&format!("{},{}", junction.x, junction.y));
+        }
+        for (id, junction) in junctions.iter().enumerate() {
+            for first in open_neighbours(junction) {
+                if !passable(junction, &first) {
+                    continue;
+                }
+
+                // The search stops at the first junction, a corridor has no branches to follow.
+                let distances = bfs_distances(
+                    [first],
+                    |current: &GridPoint| -> Vec<GridPoint> {
+                        if ids.contains_key(current) {
+                            return Vec::new();
+                        }
+                        open_neighbours(current)
+                            .into_iter()
+                            .filter(|next| next != junction && passable(current, next))
+                            .collect()
+                    },
+                    None,
+                );
+
+                for (position, steps) in distances {
+                    if let Some(&end) = ids.get(&position) {
+                        if end != id && (slopes == Slopes::Respect || id < end) {
+                            graph.add_edge_by_id(id, end, steps + 1);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            graph,
+            junctions,
+            start: 0,
+            goal: 1,
+            slopes,
+            ids,
+        })
+    }
+
+    pub fn id(&self, position: &GridPoint) -> Option<usize> {
+        self.ids.get(position).copied()
+    }
+
+    // Steps of the longest hike from start to goal that never visits a tile twice.
+    pub fn longest_hike(&self, options: &SearchOptions) -> Option<usize> {
+        longest_path(&self.graph, self.start, self.goal, options)
+    }
+
+    pub fn to_dot(&self) -> String {
+        let (mut rtn, arrow) = match self.slopes {
+            Slopes::Respect => (String::from("digraph trails {\n"), "->"),
+            Slopes::Ignore => (String::from("graph trails {\n"), "--"),
+        };
+
+        for (id, junction) in self.junctions.iter().enumerate() {
+            let shape = if id == self.start || id == self.goal {
+                "doublecircle"
+            } else {
+                "circle"
+            };
+            writeln!(
+                rtn,
+                "    {} [label=\"{},{}\", shape={}];",
+                id, junction.x, junction.y, shape
+            )
+            .unwrap();
+        }
+
+        for edge in self.graph.edges() {
+            writeln!(
+                rtn,
+                "    {} {} {} [label=\"{}\"];",
+                edge.from, arrow, edge.to, edge.weight
+            )
+            .unwrap();
+        }
+
+        rtn.push_str("}\n");
+        rtn
+    }
+}
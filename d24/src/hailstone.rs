@@ -0,0 +1,320 @@
+use std::str::FromStr;
+
+use utils::{
+    linear::{solve_integer, LinearError},
+    rational::Rational,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hailstone {
+    pub position: [i128; 3],
+    pub velocity: [i128; 3],
+}
+
+impl FromStr for Hailstone {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let triple = |part: &str| -> Result<[i128; 3], String> {
+            let values: Vec<i128> = part
+                .split(',')
+                .map(|v| {
+                    v.trim()
+                        .parse()
+                        .map_err(|_| format!("invalid number \"{}\"", v))
+                })
+                .collect::<Result<_, _>>()?;
+            values
+                .try_into()
+                .map_err(|_| format!("expected three numbers in \"{}\"", part))
+        };
+
+        let (position, velocity) = s
+            .split_once('@')
+            .ok_or_else(|| format!("expected \"position @ velocity\" in \"{}\"", s))?;
+        Ok(Self {
+            position: triple(position)?,
+            velocity: triple(velocity)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crossing<const N: usize> {
+    // Same direction on different paths.
+    Parallel,
+    // Both follow the same path.
+    Collinear,
+    // Different directions that never meet, only possible in 3-D.
+    Skew,
+    // `time` of the first and `other_time` of the second hailstone at the shared `point`.
+    Point {
+        time: Rational,
+        other_time: Rational,
+        point: [Rational; N],
+    },
+}
+
+impl<const N: usize> Crossing<N> {
+    // Whether both hailstones pass the point now or later.
+    pub fn is_in_future(&self) -> bool {
+        match self {
+            Crossing::Point {
+                time, other_time, ..
+            } => !time.is_negative() && !other_time.is_negative(),
+            _ => false,
+        }
+    }
+}
+
+fn cross_2d(a: [i128; 2], b: [i128; 2]) -> i128 {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: [i128; 3], b: [i128; 3]) -> i128 {
+    a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+}
+
+impl Hailstone {
+    pub fn position_at(&self, time: Rational) -> [Rational; 3] {
+        [0, 1, 2]
+            .map(|i| Rational::from(self.position[i]) + Rational::from(self.velocity[i]) * time)
+    }
+
+    fn offset_to(&self, other: &Self) -> [i128; 3] {
+        [0, 1, 2].map(|i| other.position[i] - self.position[i])
+    }
+
+    // Crossing of both paths in the x-y plane, z is ignored.
+    pub fn crossing_xy(&self, other: &Self) -> Crossing<2> {
+        let offset = self.offset_to(other);
+        let offset = [offset[0], offset[1]];
+        let velocity = [self.velocity[0], self.velocity[1]];
+        let other_velocity = [other.velocity[0], other.velocity[1]];
+
+        let determinant = cross_2d(velocity, other_velocity);
+        if determinant == 0 {
+            return if cross_2d(offset, velocity) == 0 {
+                Crossing::Collinear
+            } else {
+                Crossing::Parallel
+            };
+        }
+
+        let time = Rational::new(cross_2d(offset, other_velocity), determinant);
+        let position = self.position_at(time);
+        Crossing::Point {
+            time,
+            other_time: Rational::new(cross_2d(offset, velocity), determinant),
+            point: [position[0], position[1]],
+        }
+    }
+
+    pub fn crossing(&self, other: &Self) -> Crossing<3> {
+        let offset = self.offset_to(other);
+        let normal = cross(self.velocity, other.velocity);
+
+        if normal == [0; 3] {
+            return if cross(offset, self.velocity) == [0; 3] {
+                Crossing::Collinear
+            } else {
+                Crossing::Parallel
+            };
+        }
+        if dot(offset, normal) != 0 {
+            return Crossing::Skew;
+        }
+
+        let length = dot(normal, normal);
+        let time = Rational::new(dot(cross(offset, other.velocity), normal), length);
+        Crossing::Point {
+            time,
+            other_time: Rational::new(dot(cross(offset, self.velocity), normal), length),
+            point: self.position_at(time),
+        }
+    }
+
+    // When both hailstones are at the same place at the same time, zero if they always are.
+    pub fn collision_time(&self, other: &Self) -> Option<Rational> {
+        let mut rtn: Option<Rational> = None;
+        for (offset, closing) in self
+            .offset_to(other)
+            .into_iter()
+            .zip((0..3).map(|i| self.velocity[i] - other.velocity[i]))
+        {
+            if closing == 0 {
+                if offset != 0 {
+                    return None;
+                }
+                continue;
+            }
+            let time = Rational::new(offset, closing);
+            if rtn.is_some_and(|t| t != time) {
+                return None;
+            }
+            rtn = Some(time);
+        }
+        Some(rtn.unwrap_or(Rational::ZERO))
+    }
+}
+
+// Hailstones of one input and the area their crossings are counted in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hailstorm {
+    pub hailstones: Vec<Hailstone>,
+    pub area: TestArea,
+}
+
+// Square both coordinates of a crossing have to be in, bounds included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TestArea {
+    pub min: i128,
+    pub max: i128,
+}
+
+impl TestArea {
+    pub const EXAMPLE: Self = Self { min: 7, max: 27 };
+    pub const PUZZLE: Self = Self {
+        min: 200_000_000_000_000,
+        max: 400_000_000_000_000,
+    };
+
+    pub fn new(min: i128, max: i128) -> Self {
+        Self { min, max }
+    }
+
+    pub fn contains(&self, point: &[Rational]) -> bool {
+        let (min, max) = (Rational::from(self.min), Rational::from(self.max));
+        point.iter().all(|c| min <= *c && *c <= max)
+    }
+}
+
+// Pairs of hailstones whose x-y paths cross inside `area` at a time both still reach.
+pub fn count_crossings(hailstones: &[Hailstone], area: &TestArea) -> usize {
+    hailstones
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| hailstones[i + 1..].iter().map(move |b| a.crossing_xy(b)))
+        .filter(|crossing| match crossing {
+            Crossing::Point { point, .. } => crossing.is_in_future() && area.contains(point),
+            _ => false,
+        })
+        .count()
+}
+
+// Equations for a rock hitting all three hailstones, taken pairwise from the cross products of
+// relative position and velocity. Columns are px, py, pz, vx, vy, vz of the rock.
+pub fn rock_system(hailstones: [&Hailstone; 3]) -> (Vec<Vec<i128>>, Vec<i128>) {
+    let widen = |h: &Hailstone| {
+        let [x, y, z] = h.position;
+        let [v_x, v_y, v_z] = h.velocity;
+        [x, y, z, v_x, v_y, v_z]
+    };
+    let [a, b, c, d, e, f] = widen(hailstones[0]);
+    let [g, h, i, j, k, l] = widen(hailstones[1]);
+    let [m, n, o, p, q, r] = widen(hailstones[2]);
+
+    let matrix = [
+        [
+            0,
+            l - f,
+            e - k,
+            0,
+            c - i,
+            h - b,
+            e * c - b * f + h * l - k * i,
+        ],
+        [
+            0,
+            r - f,
+            e - q,
+            0,
+            c - o,
+            n - b,
+            e * c - b * f + n * r - q * o,
+        ],
+        [
+            f - l,
+            0,
+            j - d,
+            i - c,
+            0,
+            a - g,
+            a * f - d * c + j * i - g * l,
+        ],
+        [
+            f - r,
+            0,
+            p - d,
+            o - c,
+            0,
+            a - m,
+            a * f - d * c + p * o - m * r,
+        ],
+        [
+            k - e,
+            d - j,
+            0,
+            b - h,
+            g - a,
+            0,
+            d * b - a * e + g * k - j * h,
+        ],
+        [
+            q - e,
+            d - p,
+            0,
+            b - n,
+            m - a,
+            0,
+            d * b - a * e + m * q - p * n,
+        ],
+    ];
+
+    matrix
+        .into_iter()
+        .map(|row| (row[..6].to_vec(), row[6]))
+        .unzip()
+}
+
+// Integer rock that hits every hailstone at some point now or later. Uses the first triple of
+// hailstones that pins down the rock, parallel paths leave the system singular.
+pub fn find_rock(hailstones: &[Hailstone]) -> Option<Hailstone> {
+    let n = hailstones.len();
+    let triples =
+        (0..n).flat_map(|i| (i + 1..n).flat_map(move |j| (j + 1..n).map(move |k| [i, j, k])));
+
+    for triple in triples {
+        let (matrix, rhs) = rock_system(triple.map(|i| &hailstones[i]));
+        let solution = match solve_integer(&matrix, &rhs) {
+            Ok(solution) => solution,
+            Err(LinearError::Singular | LinearError::Overflow) => continue,
+            // No rock hits these three, so none hits all of them.
+            Err(_) => return None,
+        };
+
+        // The only rock that could hit these three has to hit everything else as well.
+        let values = solution
+            .iter()
+            .map(|v| v.to_integer())
+            .collect::<Option<Vec<i128>>>()?;
+        let rock = Hailstone {
+            position: [values[0], values[1], values[2]],
+            velocity: [values[3], values[4], values[5]],
+        };
+        let hits_all = hailstones.iter().all(|h| {
+            rock.collision_time(h)
+                .is_some_and(|time| !time.is_negative())
+        });
+        return hits_all.then_some(rock);
+    }
+    None
+}
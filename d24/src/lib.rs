@@ -0,0 +1,231 @@
+use std::fs;
+
+use hailstone::{count_crossings, find_rock, Hailstone, Hailstorm, TestArea};
+use utils::{answers::input_setting, solution::Solution};
+
+pub mod hailstone;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Hailstorm;
+    type Part1 = usize;
+    type Part2 = i128;
+
+    fn parse(input_file: &str) -> Self::Input {
+        let hailstones = fs::read_to_string(input_file)
+            .unwrap()
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.parse()
+                    .unwrap_or_else(|e| panic!("Could not parse {}: {}", input_file, e))
+            })
+            .collect();
+
+        // The examples use a smaller area, set next to their answers.
+        let setting = |key: &str| -> Option<i128> {
+            input_setting(input_file, key)
+                .unwrap_or_else(|e| panic!("{}", e))
+                .map(|v| {
+                    v.parse()
+                        .unwrap_or_else(|_| panic!("Invalid {} for {}: {}", key, input_file, v))
+                })
+        };
+        let area = match (setting("test_area_min"), setting("test_area_max")) {
+            (Some(min), Some(max)) => TestArea::new(min, max),
+            (None, None) => TestArea::PUZZLE,
+            _ => panic!("{} needs both test_area_min and test_area_max", input_file),
+        };
+
+        Hailstorm { hailstones, area }
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(&input.hailstones, &input.area)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(&input.hailstones)
+    }
+}
+
+fn run(hailstones: &[Hailstone], area: &TestArea) -> usize {
+    // Solve
+    let result = count_crossings(hailstones, area);
+
+    // Result
+    result
+}
+
+fn run2(hailstones: &[Hailstone]) -> i128 {
+    // Solve
+    let rock = find_rock(hailstones).expect("No rock hits every hailstone");
+
+    // Result
+    // x + y + z
+    rock.position.iter().sum()
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+    use utils::rational::Rational;
+
+    use utils::linear::{solve_integer, LinearError};
+
+    use crate::hailstone::{
+        count_crossings, find_rock, rock_system, Crossing, Hailstone, TestArea,
+    };
+    use crate::Day24;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day24>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<Day24>(file!(), "input_test", 2);
+    }
+
+    fn hailstone(s: &str) -> Hailstone {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_crossings_xy() {
+        let a = hailstone("19, 13, 30 @ -2, 1, -2");
+        let b = hailstone("18, 19, 22 @ -1, -1, -2");
+        assert_eq!(
+            a.crossing_xy(&b),
+            Crossing::Point {
+                time: Rational::new(7, 3),
+                other_time: Rational::new(11, 3),
+                point: [Rational::new(43, 3), Rational::new(46, 3)],
+            }
+        );
+
+        // The f64 slopes broke on vertical paths.
+        let vertical = hailstone("10, 0, 0 @ 0, 1, 0");
+        let horizontal = hailstone("0, 20, 0 @ 1, 0, 0");
+        let crossing = vertical.crossing_xy(&horizontal);
+        assert!(crossing.is_in_future());
+        assert_eq!(
+            crossing,
+            Crossing::Point {
+                time: Rational::from(20),
+                other_time: Rational::from(10),
+                point: [Rational::from(10), Rational::from(20)],
+            }
+        );
+        assert_eq!(
+            count_crossings(&[vertical, horizontal], &TestArea::new(0, 20)),
+            1
+        );
+        assert_eq!(
+            count_crossings(&[vertical, horizontal], &TestArea::new(0, 19)),
+            0
+        );
+
+        let behind = hailstone("10, 30, 0 @ 0, 1, 0");
+        assert!(!behind.crossing_xy(&horizontal).is_in_future());
+        assert_eq!(vertical.crossing_xy(&behind), Crossing::Collinear);
+        let beside = hailstone("11, 0, 0 @ 0, 3, 5");
+        assert_eq!(vertical.crossing_xy(&beside), Crossing::Parallel);
+
+        // Exact at the scale of the puzzle area.
+        let far = hailstone("200000000000001, 300000000000000, 0 @ 1, 0, 0");
+        let other = hailstone("300000000000000, 200000000000000, 0 @ 0, 1, 0");
+        let crossing = far.crossing_xy(&other);
+        assert_eq!(
+            crossing,
+            Crossing::Point {
+                time: Rational::from(99_999_999_999_999),
+                other_time: Rational::from(100_000_000_000_000),
+                point: [
+                    Rational::from(300_000_000_000_000),
+                    Rational::from(300_000_000_000_000)
+                ],
+            }
+        );
+        assert_eq!(count_crossings(&[far, other], &TestArea::PUZZLE), 1);
+    }
+
+    #[test]
+    fn test_crossings_3d() {
+        let a = hailstone("0, 0, 0 @ 1, 1, 1");
+        let b = hailstone("4, 0, 0 @ -1, 1, 1");
+        assert_eq!(
+            a.crossing(&b),
+            Crossing::Point {
+                time: Rational::from(2),
+                other_time: Rational::from(2),
+                point: [Rational::from(2); 3],
+            }
+        );
+        assert_eq!(a.collision_time(&b), Some(Rational::from(2)));
+
+        // Same point, different times.
+        let late = hailstone("6, 0, 0 @ -2, 2, 2");
+        assert_eq!(
+            a.crossing(&late),
+            Crossing::Point {
+                time: Rational::from(3),
+                other_time: Rational::new(3, 2),
+                point: [Rational::from(3); 3],
+            }
+        );
+        assert_eq!(a.collision_time(&late), None);
+        assert_eq!(a.crossing(&hailstone("0, 1, 0 @ 1, 0, 0")), Crossing::Skew);
+        assert_eq!(
+            a.crossing(&hailstone("0, 1, 0 @ 2, 2, 2")),
+            Crossing::Parallel
+        );
+        assert_eq!(
+            a.crossing(&hailstone("3, 3, 3 @ -1, -1, -1")),
+            Crossing::Collinear
+        );
+    }
+
+    #[test]
+    fn test_find_rock() {
+        let rock = hailstone("240000000000000, 310000000000000, 270000000000000 @ -13, 21, 7");
+
+        // Hailstones at the puzzle scale that the rock hits at known times.
+        let mut seed = 12345u64;
+        let mut random = |range: i128| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as i128 % range
+        };
+        let mut hailstones: Vec<Hailstone> = (0..20)
+            .map(|_| {
+                let time = 100_000_000_000 + random(900_000_000_000);
+                let velocity = [0, 1, 2].map(|_| random(601) - 300);
+                Hailstone {
+                    position: [0, 1, 2]
+                        .map(|i| rock.position[i] + (rock.velocity[i] - velocity[i]) * time),
+                    velocity,
+                }
+            })
+            .collect();
+        assert_eq!(find_rock(&hailstones), Some(rock));
+
+        // A second hailstone on a parallel path, so the first triple does not pin the rock down.
+        let velocity = hailstones[0].velocity;
+        let parallel = Hailstone {
+            position: [0, 1, 2]
+                .map(|i| rock.position[i] + (rock.velocity[i] - velocity[i]) * 500_000_000_000),
+            velocity,
+        };
+        hailstones.insert(1, parallel);
+        let (matrix, rhs) = rock_system([&hailstones[0], &hailstones[1], &hailstones[2]]);
+        assert_eq!(solve_integer(&matrix, &rhs), Err(LinearError::Singular));
+        assert_eq!(find_rock(&hailstones), Some(rock));
+
+        hailstones.push(hailstone("0, 0, 0 @ 1, 1, 1"));
+        assert_eq!(find_rock(&hailstones), None);
+    }
+}
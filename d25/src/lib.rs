@@ -0,0 +1,116 @@
+use std::fs;
+
+use min_cut::cut_of_size;
+use utils::{
+    graph::{EdgeKind, Graph},
+    solution::{NoSolution, Solution},
+};
+
+pub mod min_cut;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Graph;
+    type Part1 = usize;
+    type Part2 = NoSolution;
+
+    fn parse(input_file: &str) -> Self::Input {
+        Graph::from_adjacency(
+            &fs::read_to_string(input_file).unwrap(),
+            EdgeKind::Undirected,
+        )
+        .unwrap_or_else(|e| panic!("Could not parse {}: {}", input_file, e))
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(_input: &Self::Input) -> Self::Part2 {
+        NoSolution
+    }
+}
+
+fn run(wiring: &Graph) -> usize {
+    // Solve
+    let cut =
+        cut_of_size(wiring, 3).unwrap_or_else(|e| panic!("Could not split the wiring: {}", e));
+
+    // Result
+    let (a, b) = cut.side_sizes();
+    a * b
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+    use utils::get_test_input_path;
+    use utils::graph::{EdgeKind, Graph};
+    use utils::solution::Solution;
+
+    use crate::min_cut::{cut_of_size, min_cut, CutError};
+    use crate::Day25;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<Day25>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_wiring_cut() {
+        let input_path = get_test_input_path(file!());
+        let wiring = Day25::parse(input_path.to_str().unwrap());
+        let cut = cut_of_size(&wiring, 3).unwrap();
+
+        let mut cut_wires: Vec<[&str; 2]> = cut
+            .edges
+            .iter()
+            .map(|(a, b)| {
+                let mut pair = [wiring.name(*a), wiring.name(*b)];
+                pair.sort();
+                pair
+            })
+            .collect();
+        cut_wires.sort();
+        assert_eq!(cut_wires, [["bvb", "cmg"], ["hfx", "pzl"], ["jqt", "nvd"]]);
+        let mut sizes = [cut.sides[0].len(), cut.sides[1].len()];
+        sizes.sort();
+        assert_eq!(sizes, [6, 9]);
+        assert_eq!(wiring.components().len(), 1);
+
+        assert_eq!(
+            cut_of_size(&wiring, 2),
+            Err(CutError::Size {
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_min_cut() {
+        // Two complete graphs of four nodes joined by a doubled edge.
+        let mut edges: Vec<(usize, usize)> = (0..4)
+            .flat_map(|a| (a + 1..4).flat_map(move |b| [(a, b), (a + 4, b + 4)]))
+            .collect();
+        edges.extend([(3, 4), (3, 4)]);
+        let cut = min_cut(8, &edges).unwrap();
+        assert_eq!(cut.edges, [(3, 4), (3, 4)]);
+        assert_eq!(cut.side_sizes(), (4, 4));
+
+        // Cutting off a single node beats the bridge.
+        let edges = [(0, 1), (0, 2), (1, 2), (2, 3), (2, 3), (3, 4)];
+        let cut = min_cut(5, &edges).unwrap();
+        assert_eq!(cut.sides, [vec![4], vec![0, 1, 2, 3]]);
+
+        let disconnected = min_cut(4, &[(0, 1), (2, 3)]).unwrap();
+        assert_eq!(disconnected.size(), 0);
+        assert_eq!(disconnected.side_sizes(), (2, 2));
+
+        assert_eq!(min_cut(1, &[]), None);
+        let mut single: Graph = Graph::new(EdgeKind::Undirected);
+        single.intern("a");
+        assert_eq!(cut_of_size(&single, 0), Err(CutError::TooSmall));
+    }
+}
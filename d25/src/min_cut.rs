@@ -0,0 +1,138 @@
+use std::{
+    collections::{BinaryHeap, HashMap},
+    fmt,
+};
+
+use utils::graph::Graph;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    // Edges between both sides, in input order.
+    pub edges: Vec<(usize, usize)>,
+    // Sorted nodes of each side.
+    pub sides: [Vec<usize>; 2],
+}
+
+impl Cut {
+    pub fn size(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn side_sizes(&self) -> (usize, usize) {
+        (self.sides[0].len(), self.sides[1].len())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CutError {
+    // A graph needs two nodes to be cut.
+    TooSmall,
+    // The minimum cut does not have the requested size.
+    Size { expected: usize, found: usize },
+}
+
+impl fmt::Display for CutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CutError::TooSmall => write!(f, "graph has fewer than two nodes"),
+            CutError::Size { expected, found } => {
+                write!(f, "minimum cut has {} edges instead of {}", found, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CutError {}
+
+// Global minimum cut of an undirected graph with nodes `0..nodes` after Stoer-Wagner, repeated
+// edges count multiple times. Deterministic, ties go to the larger node id.
+pub fn min_cut(nodes: usize, edges: &[(usize, usize)]) -> Option<Cut> {
+    if nodes < 2 {
+        return None;
+    }
+
+    // Merged nodes keep summed weights to all their neighbours.
+    let mut weights: Vec<HashMap<usize, usize>> = vec![HashMap::new(); nodes];
+    for &(a, b) in edges.iter().filter(|(a, b)| a != b) {
+        *weights[a].entry(b).or_default() += 1;
+        *weights[b].entry(a).or_default() += 1;
+    }
+    let mut members: Vec<Vec<usize>> = (0..nodes).map(|n| vec![n]).collect();
+    let mut active: Vec<usize> = (0..nodes).collect();
+    let mut best: Option<(usize, Vec<usize>)> = None;
+
+    while active.len() > 1 {
+        // Maximum adjacency order, always add the node most connected to the ones already added.
+        let mut added = vec![false; nodes];
+        let mut connection = vec![0; nodes];
+        let mut order = Vec::with_capacity(active.len());
+        let mut queue = BinaryHeap::from([(0, active[0])]);
+        while let Some((weight, node)) = queue.pop() {
+            if added[node] || weight != connection[node] {
+                continue;
+            }
+            added[node] = true;
+            order.push(node);
+            for (&next, &edge) in weights[node].iter() {
+                if !added[next] {
+                    connection[next] += edge;
+                    queue.push((connection[next], next));
+                }
+            }
+        }
+
+        // Not connected, nothing beats cutting off the first component.
+        if order.len() < active.len() {
+            let side = order
+                .iter()
+                .flat_map(|n| members[*n].iter().copied())
+                .collect();
+            best = Some((0, side));
+            break;
+        }
+
+        let (s, t) = (order[order.len() - 2], order[order.len() - 1]);
+        if best.as_ref().is_none_or(|b| connection[t] < b.0) {
+            best = Some((connection[t], members[t].clone()));
+        }
+
+        let moved = std::mem::take(&mut members[t]);
+        members[s].extend(moved);
+        for (next, edge) in std::mem::take(&mut weights[t]) {
+            weights[next].remove(&t);
+            if next != s {
+                *weights[s].entry(next).or_default() += edge;
+                *weights[next].entry(s).or_default() += edge;
+            }
+        }
+        active.retain(|n| *n != t);
+    }
+
+    let (_, mut side) = best?;
+    side.sort_unstable();
+    let mut in_side = vec![false; nodes];
+    side.iter().for_each(|n| in_side[*n] = true);
+
+    Some(Cut {
+        edges: edges
+            .iter()
+            .filter(|(a, b)| in_side[*a] != in_side[*b])
+            .copied()
+            .collect(),
+        sides: [side, (0..nodes).filter(|n| !in_side[*n]).collect()],
+    })
+}
+
+// The minimum cut of an undirected `graph`, verified to consist of exactly `size` edges.
+// Weights are ignored, every edge counts once.
+pub fn cut_of_size<W>(graph: &Graph<W>, size: usize) -> Result<Cut, CutError> {
+    let edges: Vec<(usize, usize)> = graph.edges().iter().map(|e| (e.from, e.to)).collect();
+    let cut = min_cut(graph.len(), &edges).ok_or(CutError::TooSmall)?;
+    if cut.size() != size {
+        return Err(CutError::Size {
+            expected: size,
+            found: cut.size(),
+        });
+    }
+    Ok(cut)
+}
@@ -1,79 +1,146 @@
-use std::{process::Command, time::Instant};
-
-#[cfg(not(target_os = "windows"))]
-fn get_commands() -> Vec<String> {
-    Vec::from([
-        String::from("target/release/d01"),
-        String::from("target/release/d02"),
-        String::from("target/release/d03"),
-        String::from("target/release/d04"),
-        String::from("target/release/d05"),
-        String::from("target/release/d06"),
-        String::from("target/release/d07"),
-        String::from("target/release/d08"),
-        String::from("target/release/d09"),
-        String::from("target/release/d10"),
-        String::from("target/release/d11"),
-        String::from("target/release/d12"),
-        String::from("target/release/d13"),
-        String::from("target/release/d14"),
-        String::from("target/release/d15"),
-        String::from("target/release/d16"),
-        String::from("target/release/d17"),
-        String::from("target/release/d18"),
-        String::from("target/release/d19"),
-        String::from("target/release/d20"),
-        String::from("target/release/d21"),
-        String::from("target/release/d22"),
-        String::from("target/release/d23"),
-        String::from("target/release/d24"),
-        String::from("target/release/d25"),
-    ])
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{exit, Command},
+    sync::Mutex,
+    thread,
+    time::Instant,
+};
+
+use report::{parse_output, DayReport, Format};
+
+mod report;
+
+struct Options {
+    target_dir: PathBuf,
+    jobs: usize,
+    format: Format,
+}
+
+fn usage() -> ! {
+    eprintln!("Usage: launch_all [--jobs N | --parallel] [--format text|markdown|json] [--target-dir DIR]");
+    exit(1);
+}
+
+fn parse_options() -> Options {
+    let mut options = Options {
+        target_dir: PathBuf::from("target").join("release"),
+        jobs: 1,
+        format: Format::Text,
+    };
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--jobs" => {
+                options.jobs = match args.next().and_then(|j| j.parse().ok()) {
+                    Some(jobs) if jobs > 0 => jobs,
+                    _ => usage(),
+                }
+            }
+            "--parallel" => {
+                options.jobs = thread::available_parallelism()
+                    .map(|n| n.get())
+                    .unwrap_or(1)
+            }
+            "--format" => {
+                options.format = match args.next().as_deref() {
+                    Some("text") => Format::Text,
+                    Some("markdown") => Format::Markdown,
+                    Some("json") => Format::Json,
+                    _ => usage(),
+                }
+            }
+            "--target-dir" => options.target_dir = args.next().unwrap_or_else(|| usage()).into(),
+            _ => usage(),
+        }
+    }
+
+    options
 }
 
-#[cfg(target_os = "windows")]
-fn get_commands() -> Vec<String> {
-    Vec::from([
-        String::from("target/release/d01.exe"),
-        String::from("target/release/d02.exe"),
-        String::from("target/release/d03.exe"),
-        String::from("target/release/d04.exe"),
-        String::from("target/release/d05.exe"),
-        String::from("target/release/d06.exe"),
-        String::from("target/release/d07.exe"),
-        String::from("target/release/d08.exe"),
-        String::from("target/release/d09.exe"),
-        String::from("target/release/d10.exe"),
-        String::from("target/release/d11.exe"),
-        String::from("target/release/d12.exe"),
-        String::from("target/release/d13.exe"),
-        String::from("target/release/d14.exe"),
-        String::from("target/release/d15.exe"),
-        String::from("target/release/d16.exe"),
-        String::from("target/release/d17.exe"),
-        String::from("target/release/d18.exe"),
-        String::from("target/release/d19.exe"),
-        String::from("target/release/d20.exe"),
-        String::from("target/release/d21.exe"),
-        String::from("target/release/d22.exe"),
-        String::from("target/release/d23.exe"),
-        String::from("target/release/d24.exe"),
-        String::from("target/release/d25.exe"),
-    ])
+// Every `dNN` executable in the target directory, ordered by day.
+fn find_day_binaries(target_dir: &Path) -> Vec<(usize, PathBuf)> {
+    let mut rtn: Vec<(usize, PathBuf)> = fs::read_dir(target_dir)
+        .unwrap_or_else(|e| panic!("Could not read {}: {}", target_dir.display(), e))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_file())
+        .filter_map(|path| {
+            let file_name = path.file_name()?.to_str()?;
+            let name = file_name.strip_suffix(env::consts::EXE_SUFFIX)?;
+            let day = name.strip_prefix('d')?;
+            if day.len() != 2 {
+                return None;
+            }
+            Some((day.parse().ok()?, path))
+        })
+        .collect();
+    rtn.sort();
+    rtn
+}
+
+fn run_day(day: usize, binary: &Path) -> DayReport {
+    let start = Instant::now();
+    let output = Command::new(binary).output();
+    let duration = start.elapsed();
+
+    match output {
+        Ok(output) => {
+            let mut report = parse_output(day, &String::from_utf8_lossy(&output.stdout));
+            report.duration = duration;
+            if !output.status.success() {
+                report.error = Some(match output.status.code() {
+                    Some(code) => format!("exit code {}", code),
+                    None => "terminated by signal".to_string(),
+                });
+            }
+            report
+        }
+        Err(e) => DayReport {
+            day,
+            duration,
+            error: Some(e.to_string()),
+            ..Default::default()
+        },
+    }
 }
 
 fn main() {
-    let commands = get_commands();
+    let options = parse_options();
+    let binaries = find_day_binaries(&options.target_dir);
+    if binaries.is_empty() {
+        eprintln!(
+            "No day binaries found in {}, run `cargo build --release` first.",
+            options.target_dir.display()
+        );
+        exit(1);
+    }
 
     let overall_start = Instant::now();
-    for (idx, command) in commands.iter().enumerate() {
-        let start = Instant::now();
-        unsafe {
-            Command::new(command).output().unwrap_unchecked();
+
+    // Workers pull the next day from the shared queue until it is empty.
+    let queue = Mutex::new(binaries.into_iter().rev().collect::<Vec<_>>());
+    let reports = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..options.jobs {
+            scope.spawn(|| loop {
+                let next = queue.lock().unwrap().pop();
+                let Some((day, binary)) = next else {
+                    break;
+                };
+                let report = run_day(day, &binary);
+                reports.lock().unwrap().push(report);
+            });
         }
-        let stop = start.elapsed();
-        println!("Day {} took {} milliseconds", idx + 1, stop.as_millis());
+    });
+
+    let overall_duration = overall_start.elapsed();
+    let mut reports = reports.into_inner().unwrap();
+    reports.sort_by_key(|r| r.day);
+
+    print!("{}", options.format.render(&reports, overall_duration));
+
+    if reports.iter().any(|r| r.error.is_some()) {
+        exit(1);
     }
-    let overall_stop = overall_start.elapsed();
-    println!("Overall it took {} milliseconds or {} seconds", overall_stop.as_millis(), overall_stop.as_secs());
 }
@@ -0,0 +1,240 @@
+use std::{fmt::Write, time::Duration};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartReport {
+    pub part: usize,
+    pub answer: String,
+    pub duration: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayReport {
+    pub day: usize,
+    // Wall clock time of the whole process.
+    pub duration: Duration,
+    pub parse_duration: Option<Duration>,
+    pub parts: Vec<PartReport>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Markdown,
+    Json,
+}
+
+fn parse_micros(value: &str) -> Option<Duration> {
+    value
+        .strip_suffix(" microseconds")
+        .and_then(|v| v.parse().ok())
+        .map(Duration::from_micros)
+}
+
+// Reads the lines printed by `utils::solution::run`.
+pub fn parse_output(day: usize, stdout: &str) -> DayReport {
+    let mut report = DayReport {
+        day,
+        ..Default::default()
+    };
+
+    for line in stdout.lines() {
+        if let Some(rest) = line.strip_prefix("Parsing took ") {
+            report.parse_duration = parse_micros(rest);
+        } else if let Some(rest) = line.strip_prefix("Result of part ") {
+            if let Some((part, answer)) = rest.split_once(" is ") {
+                report.parts.push(PartReport {
+                    part: part.parse().unwrap_or_default(),
+                    answer: answer.to_string(),
+                    duration: None,
+                });
+            }
+        } else if let Some(rest) = line.strip_prefix("Part ") {
+            if let Some((part, took)) = rest.split_once(" took ") {
+                let part = part.parse().unwrap_or_default();
+                if let Some(p) = report.parts.iter_mut().find(|p| p.part == part) {
+                    p.duration = parse_micros(took);
+                }
+            }
+        }
+    }
+
+    report
+}
+
+fn format_duration(duration: Option<Duration>) -> String {
+    match duration {
+        Some(duration) => format!("{:.3} ms", duration.as_secs_f64() * 1000.0),
+        None => "-".to_string(),
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut rtn = String::with_capacity(value.len() + 2);
+    rtn.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => rtn.push_str("\\\""),
+            '\\' => rtn.push_str("\\\\"),
+            '\n' => rtn.push_str("\\n"),
+            c if c.is_control() => write!(rtn, "\\u{:04x}", c as u32).unwrap(),
+            c => rtn.push(c),
+        }
+    }
+    rtn.push('"');
+    rtn
+}
+
+fn json_micros(duration: Option<Duration>) -> String {
+    duration
+        .map(|d| d.as_micros().to_string())
+        .unwrap_or_else(|| "null".to_string())
+}
+
+impl DayReport {
+    fn part(&self, part: usize) -> Option<&PartReport> {
+        self.parts.iter().find(|p| p.part == part)
+    }
+
+    fn status(&self) -> &str {
+        self.error.as_deref().unwrap_or("ok")
+    }
+
+    fn columns(&self) -> Vec<String> {
+        let mut rtn = vec![
+            format!("{:0>2}", self.day),
+            self.status().to_string(),
+            format_duration(self.parse_duration),
+        ];
+        for part in 1..=2 {
+            let report = self.part(part);
+            rtn.push(report.map(|p| p.answer.clone()).unwrap_or_default());
+            rtn.push(format_duration(report.and_then(|p| p.duration)));
+        }
+        rtn.push(format_duration(Some(self.duration)));
+        rtn
+    }
+}
+
+const HEADER: [&str; 8] = [
+    "Day", "Status", "Parse", "Part 1", "Time 1", "Part 2", "Time 2", "Total",
+];
+
+impl Format {
+    pub fn render(&self, reports: &[DayReport], overall: Duration) -> String {
+        match self {
+            Format::Text => render_text(reports, overall),
+            Format::Markdown => render_markdown(reports, overall),
+            Format::Json => render_json(reports, overall),
+        }
+    }
+}
+
+fn render_text(reports: &[DayReport], overall: Duration) -> String {
+    let rows: Vec<Vec<String>> = reports.iter().map(|r| r.columns()).collect();
+    let widths: Vec<usize> = (0..HEADER.len())
+        .map(|i| {
+            rows.iter()
+                .map(|r| r[i].len())
+                .chain([HEADER[i].len()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+
+    let mut rtn = String::new();
+    let header: Vec<String> = HEADER.iter().map(|h| h.to_string()).collect();
+    for row in [header].iter().chain(rows.iter()) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(c, w)| format!("{:<w$}", c, w = w))
+            .collect();
+        writeln!(rtn, "{}", line.join("  ").trim_end()).unwrap();
+    }
+    writeln!(
+        rtn,
+        "Overall it took {} milliseconds or {} seconds",
+        overall.as_millis(),
+        overall.as_secs()
+    )
+    .unwrap();
+    rtn
+}
+
+fn render_markdown(reports: &[DayReport], overall: Duration) -> String {
+    let mut rtn = String::new();
+    writeln!(rtn, "| {} |", HEADER.join(" | ")).unwrap();
+    writeln!(rtn, "|{}", "---|".repeat(HEADER.len())).unwrap();
+    for report in reports {
+        let columns: Vec<String> = report
+            .columns()
+            .iter()
+            .map(|c| c.replace('|', "\\|"))
+            .collect();
+        writeln!(rtn, "| {} |", columns.join(" | ")).unwrap();
+    }
+    writeln!(rtn).unwrap();
+    writeln!(rtn, "Overall: {}", format_duration(Some(overall))).unwrap();
+    rtn
+}
+
+fn render_json(reports: &[DayReport], overall: Duration) -> String {
+    let days: Vec<String> = reports
+        .iter()
+        .map(|r| {
+            let parts: Vec<String> = r
+                .parts
+                .iter()
+                .map(|p| {
+                    format!(
+                        "{{\"part\":{},\"answer\":{},\"micros\":{}}}",
+                        p.part,
+                        json_string(&p.answer),
+                        json_micros(p.duration)
+                    )
+                })
+                .collect();
+            format!(
+                "{{\"day\":{},\"success\":{},\"error\":{},\"micros\":{},\"parse_micros\":{},\"parts\":[{}]}}",
+                r.day,
+                r.error.is_none(),
+                r.error
+                    .as_deref()
+                    .map(json_string)
+                    .unwrap_or_else(|| "null".to_string()),
+                r.duration.as_micros(),
+                json_micros(r.parse_duration),
+                parts.join(",")
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\"micros\":{},\"days\":[{}]}}\n",
+        overall.as_micros(),
+        days.join(",")
+    )
+}
+
+#[cfg(test)]
+mod report_test {
+    use std::time::Duration;
+
+    use crate::report::{parse_output, Format};
+
+    #[test]
+    fn test_parse_output() {
+        let stdout = "\"input.txt\"\nParsing took 12 microseconds\nResult of part 1 is 142\nPart 1 took 3 microseconds\nResult of part 2 is no solution\nPart 2 took 0 microseconds\n";
+        let report = parse_output(1, stdout);
+        assert_eq!(report.parse_duration, Some(Duration::from_micros(12)));
+        assert_eq!(report.parts.len(), 2);
+        assert_eq!(report.parts[0].answer, "142");
+        assert_eq!(report.parts[0].duration, Some(Duration::from_micros(3)));
+        assert_eq!(report.parts[1].answer, "no solution");
+
+        let json = Format::Json.render(&[report], Duration::from_micros(20));
+        assert!(json.starts_with("{\"micros\":20,\"days\":[{\"day\":1,\"success\":true,"));
+        assert!(json.contains("{\"part\":2,\"answer\":\"no solution\",\"micros\":0}"));
+    }
+}
@@ -4,7 +4,9 @@ fn main() {
   let main_path = Path::new(".");
   let template_folder = &main_path.join("template");
   let copy_main_from = &template_folder.join("main.rs");
+  let copy_lib_from = &template_folder.join("lib.rs");
   let copy_input_from = &template_folder.join("input.txt");
+  let copy_answers_from = &template_folder.join("answers.toml");
   for i in 1..26 {
 
       let folder_name = format!("d{:0>2}", i);
@@ -16,13 +18,22 @@ fn main() {
       
       let input_folder = sub_project_folder.join("input");
       fs::create_dir_all(&input_folder).unwrap();
-      let copy_main_to = &sub_project_folder.join("src").join("main.rs");
+      let source_folder = sub_project_folder.join("src");
+      fs::create_dir_all(&source_folder).unwrap();
+      let copy_main_to = &source_folder.join("main.rs");
+      let copy_lib_to = &source_folder.join("lib.rs");
       let copy_input_to = &input_folder.join("input.txt");
       let copy_input_test_to = &input_folder.join("input_test.txt");
+      let copy_answers_to = &input_folder.join("answers.toml");
 
 
-      fs::copy(copy_main_from, copy_main_to).unwrap();
+      let day_number = format!("{:0>2}", i);
+      for (from, to) in [(copy_main_from, copy_main_to), (copy_lib_from, copy_lib_to)] {
+        let content = fs::read_to_string(from).unwrap().replace("XX", &day_number);
+        fs::write(to, content).unwrap();
+      }
       fs::copy(copy_input_from, copy_input_to).unwrap();
       fs::copy(copy_input_from, copy_input_test_to).unwrap();
+      fs::copy(copy_answers_from, copy_answers_to).unwrap();
   }
 }
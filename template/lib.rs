@@ -0,0 +1,60 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use utils::solution::Solution;
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        let file = File::open(input_file).unwrap();
+        let reader = BufReader::new(file);
+
+        reader
+            .lines()
+            .map(|line| line.unwrap().trim().to_string())
+            .collect()
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn run(lines: &[String]) -> usize {
+    // Preamble
+    let result = 0;
+    // Solve
+    for line in lines {}
+    // Result
+    result
+}
+
+fn run2(_lines: &[String]) -> usize {
+    0
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::DayXX;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<DayXX>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<DayXX>(file!(), "input_test", 2);
+    }
+}
@@ -0,0 +1,52 @@
+use std::fs;
+
+use utils::solution::Solution;
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input_file: &str) -> Self::Input {
+        fs::read_to_string(input_file).unwrap().trim().to_string()
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn run(line: &str) -> usize {
+    // Preamble
+    let result: usize = 0;
+    // Solve
+    // Result
+    result
+}
+
+fn run2(_line: &str) -> usize {
+    0
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::DayXX;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<DayXX>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<DayXX>(file!(), "input_test", 2);
+    }
+}
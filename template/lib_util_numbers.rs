@@ -0,0 +1,50 @@
+use utils::{parse_into_i64_vector_vector, solution::Solution};
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    type Input = Vec<Vec<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input_file: &str) -> Self::Input {
+        parse_into_i64_vector_vector(input_file)
+    }
+
+    fn part_1(input: &Self::Input) -> Self::Part1 {
+        run(input)
+    }
+
+    fn part_2(input: &Self::Input) -> Self::Part2 {
+        run2(input)
+    }
+}
+
+fn run(values: &[Vec<i64>]) -> i64 {
+    // Preamble
+    let result = 0;
+    // Solve
+    // Result
+    result
+}
+
+fn run2(_values: &[Vec<i64>]) -> i64 {
+    0
+}
+
+#[cfg(test)]
+mod main_test {
+    use utils::answers::check_answer;
+
+    use crate::DayXX;
+
+    #[test]
+    fn test_input_part_1() {
+        check_answer::<DayXX>(file!(), "input_test", 1);
+    }
+
+    #[test]
+    fn test_input_part_2() {
+        check_answer::<DayXX>(file!(), "input_test", 2);
+    }
+}
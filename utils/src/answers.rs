@@ -0,0 +1,146 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    get_input_file_path,
+    parse_error::{ParseError, ParseResult},
+    solution::Solution,
+};
+
+// Expected answers per input file, read from `input/answers.toml`:
+//
+// [input_test]
+// part_1 = 142
+//
+// [input_test_2]
+// part_2 = "281"
+// steps = 6
+//
+// Keys other than `part_1` and `part_2` are settings a solution reads for that input.
+// Only sections and `key = value` lines are supported.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    sections: HashMap<String, HashMap<String, String>>,
+}
+
+impl Answers {
+    pub fn parse(input_file: &str, content: &str) -> ParseResult<Self> {
+        let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
+        let mut section = String::new();
+
+        for (idx, line) in content.lines().enumerate() {
+            let line = line.trim();
+            let invalid = || ParseError::InvalidToken {
+                path: PathBuf::from(input_file),
+                line: idx + 1,
+                column: 1,
+                token: line.to_string(),
+                expected: "answers entry",
+            };
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[') {
+                section = name
+                    .strip_suffix(']')
+                    .ok_or_else(invalid)?
+                    .trim()
+                    .to_string();
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(invalid)?;
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+
+            sections
+                .entry(section.clone())
+                .or_default()
+                .insert(key.trim().to_string(), value.to_string());
+        }
+
+        Ok(Self { sections })
+    }
+
+    // A missing file means no answers are known yet.
+    pub fn load(input_file: &str) -> ParseResult<Self> {
+        match fs::read_to_string(input_file) {
+            Ok(content) => Self::parse(input_file, &content),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(ParseError::Io {
+                path: PathBuf::from(input_file),
+                source: e,
+            }),
+        }
+    }
+
+    pub fn expected(&self, input_name: &str, part: usize) -> Option<&str> {
+        self.setting(input_name, &format!("part_{}", part))
+    }
+
+    pub fn setting(&self, input_name: &str, key: &str) -> Option<&str> {
+        self.sections
+            .get(input_name)
+            .and_then(|s| s.get(key))
+            .map(|s| s.as_str())
+    }
+}
+
+pub fn get_answers_path(input_file: &Path) -> PathBuf {
+    input_file.with_file_name("answers.toml")
+}
+
+// The name of the input inside answers.toml, i.e. the file name without `.txt`.
+pub fn get_input_name(input_file: &Path) -> String {
+    input_file
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+// The setting `key` of `input_file` from the answers.toml next to it.
+pub fn input_setting(input_file: &str, key: &str) -> ParseResult<Option<String>> {
+    let input_path = Path::new(input_file);
+    let answers_path = get_answers_path(input_path);
+    let answers = Answers::load(answers_path.to_str().unwrap())?;
+    Ok(answers
+        .setting(&get_input_name(input_path), key)
+        .map(|s| s.to_string()))
+}
+
+pub fn check_answer<S: Solution>(src_path: &str, input_name: &str, part: usize) {
+    let input_path = get_input_file_path(src_path, &format!("{}.txt", input_name));
+    let answers_path = get_answers_path(&input_path);
+    let answers = Answers::load(answers_path.to_str().unwrap()).unwrap_or_else(|e| panic!("{}", e));
+
+    let expected = answers.expected(input_name, part).unwrap_or_else(|| {
+        panic!(
+            "No answer for part {} of {} in {}",
+            part,
+            input_name,
+            answers_path.display()
+        )
+    });
+
+    let input = S::parse(input_path.to_str().unwrap());
+    let actual = match part {
+        1 => S::part_1(&input).to_string(),
+        2 => S::part_2(&input).to_string(),
+        _ => panic!("There is no part {}", part),
+    };
+
+    assert_eq!(
+        actual, expected,
+        "Wrong answer for part {} of {}",
+        part, input_name
+    );
+}
@@ -0,0 +1,122 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+use crate::solution::Solution;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// Counts allocations, a binary opts in with
+// `#[global_allocator] static ALLOCATOR: CountingAllocator = CountingAllocator;`
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+pub fn allocations() -> usize {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    // Average per iteration, zero without the CountingAllocator.
+    pub allocations: usize,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>, allocations: usize) -> Self {
+        samples.sort_unstable();
+        // Nearest-rank percentile.
+        let percentile = |p: usize| {
+            let rank = (p * samples.len()).div_ceil(100).max(1);
+            samples[rank - 1]
+        };
+
+        Self {
+            min: samples[0],
+            median: percentile(50),
+            p95: percentile(95),
+            allocations,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BenchResult {
+    // Includes reading the input file.
+    pub parse: Stats,
+    pub part_1: Stats,
+    pub part_2: Stats,
+}
+
+pub type Benchmark = fn(&str, &BenchConfig) -> BenchResult;
+
+fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    let iterations = config.iterations.max(1);
+    let mut samples = Vec::with_capacity(iterations);
+    let allocations_before = allocations();
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let result = f();
+        samples.push(start.elapsed());
+        // Dropping is not part of the measurement.
+        black_box(result);
+    }
+    let allocations = (allocations() - allocations_before) / iterations;
+
+    Stats::from_samples(samples, allocations)
+}
+
+pub fn bench<S: Solution>(input_file: &str, config: &BenchConfig) -> BenchResult {
+    let parse = measure(config, || S::parse(black_box(input_file)));
+    let input = S::parse(input_file);
+
+    BenchResult {
+        parse,
+        part_1: measure(config, || S::part_1(black_box(&input))),
+        part_2: measure(config, || S::part_2(black_box(&input))),
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Repeat {
+    // Number of steps before the cycle is entered.
+    pub prefix: usize,
+    pub period: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<S> {
+    // None if the requested state came up before the cycle was found.
+    pub repeat: Option<Repeat>,
+    // The state after the requested number of steps.
+    pub state: S,
+}
+
+// Remembers every state, finds the cycle with the fewest step calls. Stops without a cycle once
+// the requested state is reached, so `step` does not have to repeat.
+pub fn detect_cycle<S, F>(initial: S, mut step: F, steps: usize) -> Cycle<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    // Keyed by hash so every state is only stored once, in the history.
+    let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+    let mut history: Vec<S> = Vec::new();
+    let mut current = initial;
+
+    loop {
+        if history.len() == steps {
+            return Cycle {
+                repeat: None,
+                state: current,
+            };
+        }
+
+        let mut hasher = DefaultHasher::new();
+        current.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let candidates = seen.entry(hash).or_default();
+        if let Some(&prefix) = candidates.iter().find(|&&i| history[i] == current) {
+            // Anything before `steps` would have returned above, so the cycle is entered by then.
+            let period = history.len() - prefix;
+            let state = history.swap_remove(prefix + (steps - prefix) % period);
+            return Cycle {
+                repeat: Some(Repeat { prefix, period }),
+                state,
+            };
+        }
+
+        candidates.push(history.len());
+        let next = step(&current);
+        history.push(current);
+        current = next;
+    }
+}
+
+// Brent's algorithm, only keeps a couple of states alive but calls `step` more often. Stops
+// without a cycle once the requested state is reached, like `detect_cycle`.
+pub fn detect_cycle_brent<S, F>(initial: S, mut step: F, steps: usize) -> Cycle<S>
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    if steps == 0 {
+        return Cycle {
+            repeat: None,
+            state: initial,
+        };
+    }
+
+    // Find the period by racing a hare against a tortoise that teleports at powers of two.
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    // The hare is always the furthest state so far.
+    let mut hare_steps = 1;
+    while tortoise != hare {
+        if hare_steps == steps {
+            return Cycle {
+                repeat: None,
+                state: hare,
+            };
+        }
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        hare_steps += 1;
+        period += 1;
+    }
+
+    // Find the start of the cycle with two pointers that are one period apart.
+    let mut prefix = 0;
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    // The tortoise now sits at the start of the cycle, which the hare passed before `steps`.
+    let mut state = tortoise;
+    for _ in 0..(steps - prefix) % period {
+        state = step(&state);
+    }
+
+    Cycle {
+        repeat: Some(Repeat { prefix, period }),
+        state,
+    }
+}
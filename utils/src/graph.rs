@@ -0,0 +1,213 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Write},
+};
+
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeKind {
+    Directed,
+    Undirected,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge<W> {
+    pub from: NodeId,
+    pub to: NodeId,
+    pub weight: W,
+}
+
+// Graph over named nodes, every name is interned to a dense id in order of appearance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<W = usize> {
+    kind: EdgeKind,
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    edges: Vec<Edge<W>>,
+    // Indices into `edges` leaving every node, undirected edges leave both ends.
+    outgoing: Vec<Vec<usize>>,
+    incoming: Vec<Vec<usize>>,
+}
+
+impl<W> Graph<W> {
+    pub fn new(kind: EdgeKind) -> Self {
+        Self {
+            kind,
+            names: Vec::new(),
+            ids: HashMap::new(),
+            edges: Vec::new(),
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+        }
+    }
+
+    pub fn kind(&self) -> EdgeKind {
+        self.kind
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    // Id of `name`, added as a new node if it is not known yet.
+    pub fn intern(&mut self, name: &str) -> NodeId {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), self.names.len() - 1);
+        self.outgoing.push(Vec::new());
+        self.incoming.push(Vec::new());
+        self.names.len() - 1
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn add_edge(&mut self, from: &str, to: &str, weight: W) {
+        let from = self.intern(from);
+        let to = self.intern(to);
+        self.add_edge_by_id(from, to, weight);
+    }
+
+    pub fn add_edge_by_id(&mut self, from: NodeId, to: NodeId, weight: W) {
+        let index = self.edges.len();
+        self.edges.push(Edge { from, to, weight });
+        self.outgoing[from].push(index);
+        self.incoming[to].push(index);
+        if self.kind == EdgeKind::Undirected && from != to {
+            self.outgoing[to].push(index);
+            self.incoming[from].push(index);
+        }
+    }
+
+    // Every edge once, in the order they were added.
+    pub fn edges(&self) -> &[Edge<W>] {
+        &self.edges
+    }
+
+    // The other end and weight of every edge leaving `id`.
+    pub fn neighbours(&self, id: NodeId) -> impl Iterator<Item = (NodeId, &W)> {
+        self.outgoing[id].iter().map(move |index| {
+            let edge = &self.edges[*index];
+            if edge.from == id {
+                (edge.to, &edge.weight)
+            } else {
+                (edge.from, &edge.weight)
+            }
+        })
+    }
+
+    // Number of edges leaving `id`, all edges touching it for undirected graphs.
+    pub fn degree(&self, id: NodeId) -> usize {
+        self.outgoing[id].len()
+    }
+
+    pub fn in_degree(&self, id: NodeId) -> usize {
+        self.incoming[id].len()
+    }
+
+    // Connected components, ignoring directions. Components and their nodes are ordered by id.
+    pub fn components(&self) -> Vec<Vec<NodeId>> {
+        let mut component = vec![usize::MAX; self.len()];
+        let mut rtn: Vec<Vec<NodeId>> = Vec::new();
+
+        for start in 0..self.len() {
+            if component[start] != usize::MAX {
+                continue;
+            }
+            component[start] = rtn.len();
+            let mut nodes = vec![start];
+            let mut stack = vec![start];
+            while let Some(node) = stack.pop() {
+                let touching = self.outgoing[node].iter().chain(self.incoming[node].iter());
+                for edge in touching.map(|index| &self.edges[*index]) {
+                    for next in [edge.from, edge.to] {
+                        if component[next] == usize::MAX {
+                            component[next] = rtn.len();
+                            nodes.push(next);
+                            stack.push(next);
+                        }
+                    }
+                }
+            }
+            nodes.sort_unstable();
+            rtn.push(nodes);
+        }
+
+        rtn
+    }
+}
+
+impl<W: Display> Graph<W> {
+    pub fn to_dot(&self) -> String {
+        let (mut rtn, arrow) = match self.kind {
+            EdgeKind::Directed => (String::from("digraph {\n"), "->"),
+            EdgeKind::Undirected => (String::from("graph {\n"), "--"),
+        };
+        for name in self.names.iter() {
+            writeln!(rtn, "    \"{}\";", name).unwrap();
+        }
+        for edge in self.edges.iter() {
+            writeln!(
+                rtn,
+                "    \"{}\" {} \"{}\" [label=\"{}\"];",
+                self.names[edge.from], arrow, self.names[edge.to], edge.weight
+            )
+            .unwrap();
+        }
+        rtn.push_str("}\n");
+        rtn
+    }
+}
+
+impl Graph<usize> {
+    // One node per line followed by its neighbours, either as `name: a b c` or as `a -> b, c`.
+    // Every edge gets weight 1.
+    pub fn from_adjacency(s: &str, kind: EdgeKind) -> Result<Self, String> {
+        let mut rtn = Self::new(kind);
+
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let error = |reason: &str| format!("line {}: {} in \"{}\"", idx + 1, reason, line);
+
+            let (from, targets): (&str, Vec<&str>) = if let Some((from, to)) = line.split_once("->")
+            {
+                (from, to.split(',').map(|t| t.trim()).collect())
+            } else if let Some((from, to)) = line.split_once(':') {
+                (from, to.split_whitespace().collect())
+            } else {
+                return Err(error("expected \"name: a b\" or \"name -> a, b\""));
+            };
+
+            let from = from.trim();
+            if from.is_empty() || targets.iter().any(|t| t.is_empty()) {
+                return Err(error("empty node name"));
+            }
+            let from = rtn.intern(from);
+            for target in targets {
+                let to = rtn.intern(target);
+                rtn.add_edge_by_id(from, to, 1);
+            }
+        }
+
+        Ok(rtn)
+    }
+}
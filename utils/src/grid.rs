@@ -1,18 +1,80 @@
 use std::{
+    error::Error,
     fmt::Display,
     hash::{DefaultHasher, Hash, Hasher},
 };
 
 use crate::grid_point::GridPoint;
 
+// Row-major storage, every row has exactly `width` elements.
 #[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Grid<T> {
-    pub data: Vec<Vec<T>>,
+    width: usize,
+    height: usize,
+    data: Vec<T>,
 }
 
-impl<T> From<Vec<Vec<T>>> for Grid<T> {
-    fn from(value: Vec<Vec<T>>) -> Self {
-        Self { data: value }
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    WrongLength {
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} elements but {} were expected",
+                row, found, expected
+            ),
+            GridError::WrongLength { expected, found } => write!(
+                f,
+                "grid data has {} elements but {} were expected",
+                found, expected
+            ),
+        }
+    }
+}
+
+impl Error for GridError {}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = GridError;
+
+    fn try_from(value: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let height = value.len();
+        let width = value.first().map(|row| row.len()).unwrap_or(0);
+
+        if let Some((row, found)) = value
+            .iter()
+            .map(|row| row.len())
+            .enumerate()
+            .find(|(_, len)| *len != width)
+        {
+            return Err(GridError::RaggedRow {
+                row,
+                expected: width,
+                found,
+            });
+        }
+
+        Ok(Self {
+            width,
+            height,
+            data: value.into_iter().flatten().collect(),
+        })
     }
 }
 
@@ -22,31 +84,84 @@ impl<T> Grid<T> {
         T: Clone,
     {
         Self {
-            data: vec![vec![fill; width]; height],
+            width,
+            height,
+            data: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_vec(width: usize, height: usize, data: Vec<T>) -> Result<Self, GridError> {
+        if data.len() != width * height {
+            return Err(GridError::WrongLength {
+                expected: width * height,
+                found: data.len(),
+            });
         }
+
+        Ok(Self {
+            width,
+            height,
+            data,
+        })
     }
 
     pub fn get_max_x(&self) -> usize {
-        self.data.iter().map(|i| i.len()).max().unwrap_or(0)
+        self.width
     }
 
     pub fn get_max_y(&self) -> usize {
-        self.data.len()
+        self.height
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    fn index_of(&self, x: usize, y: usize) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
     }
 
     pub fn get(&self, x: usize, y: usize) -> Option<&T> {
-        self.data.get(y).and_then(|i| i.get(x))
+        self.index_of(x, y).map(|i| &self.data[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_of(x, y).map(|i| &mut self.data[i])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.data.iter().skip(x).step_by(self.width)
     }
 
     pub fn count_for(&self, value: &T) -> usize
     where
         T: PartialEq,
     {
-        self.data.iter().flatten().filter(|i| *i == value).count()
+        self.data.iter().filter(|i| *i == value).count()
     }
 
     pub fn set(&mut self, x: usize, y: usize, value: T) {
-        self.data[y][x] = value;
+        let index = self
+            .index_of(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the grid", x, y));
+        self.data[index] = value;
     }
 
     pub fn get_from_point(&self, point: &GridPoint) -> Option<&T> {
@@ -57,53 +172,73 @@ impl<T> Grid<T> {
         self.set(point.x, point.y, value)
     }
 
+    pub fn insert_row(&mut self, y: usize, row: Vec<T>) {
+        assert!(
+            row.len() == self.width,
+            "row has {} elements but {} were expected",
+            row.len(),
+            self.width
+        );
+        let index = y * self.width;
+        self.data.splice(index..index, row);
+        self.height += 1;
+    }
+
+    pub fn insert_column(&mut self, x: usize, fill: T)
+    where
+        T: Clone,
+    {
+        assert!(x <= self.width, "column {} out of bounds", x);
+        let mut new_data = Vec::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            new_data.extend_from_slice(&row[..x]);
+            new_data.push(fill.clone());
+            new_data.extend_from_slice(&row[x..]);
+        }
+        self.data = new_data;
+        self.width += 1;
+    }
+
     pub fn create_sub_grid(&self, x: usize, y: usize, width: usize, height: usize) -> Self
     where
         T: Copy,
     {
-        let mut new_data: Vec<Vec<T>> = Vec::with_capacity(height);
-        for y in y..y + width {
-            let mut row = Vec::with_capacity(width);
-            for x in x..x + width {
-                let point = self.get(x, y).unwrap();
-                row.push(*point);
-            }
-            new_data.push(row);
+        let mut new_data: Vec<T> = Vec::with_capacity(width * height);
+        for y in y..y + height {
+            new_data.extend_from_slice(&self.row(y)[x..x + width]);
+        }
+        Grid {
+            width,
+            height,
+            data: new_data,
         }
-        Grid { data: new_data }
     }
 
     pub fn get_sub_grid(&self, x: usize, y: usize, width: usize, height: usize) -> Grid<&T> {
-        let mut new_data: Vec<Vec<&T>> = Vec::with_capacity(height);
-        for y in y..y + width {
-            let mut row = Vec::with_capacity(width);
-            for x in x..x + width {
-                let point = self.get(x, y).unwrap();
-                row.push(point);
-            }
-            new_data.push(row);
+        let mut new_data: Vec<&T> = Vec::with_capacity(width * height);
+        for y in y..y + height {
+            new_data.extend(self.row(y)[x..x + width].iter());
+        }
+        Grid {
+            width,
+            height,
+            data: new_data,
         }
-        Grid { data: new_data }
     }
 
     pub fn print_data(&self)
     where
         T: Display,
     {
-        for row in self.data.iter() {
-            for i in row.iter() {
-                print!("{}", *i);
-            }
-            println!();
-        }
+        print!("{}", self.grid_to_string());
     }
 
     pub fn grid_to_string(&self) -> String
     where
         T: Display,
     {
-        let mut rtn = String::with_capacity(self.get_max_x() * self.get_max_y() + self.get_max_y());
-        for row in self.data.iter() {
+        let mut rtn = String::with_capacity(self.width * self.height + self.height);
+        for row in self.rows() {
             for i in row.iter() {
                 rtn.push_str(&format!("{}", *i));
             }
@@ -116,28 +251,18 @@ impl<T> Grid<T> {
     where
         T: std::cmp::PartialEq,
     {
-        let mut rtn = Vec::new();
-        for (y, row) in self.data.iter().enumerate() {
-            for (x, column) in row.iter().enumerate() {
-                if column == value {
-                    rtn.push(GridPoint::new(x, y));
-                }
-            }
-        }
-        rtn
+        self.data
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| *i == value)
+            .map(|(i, _)| GridPoint::new(i % self.width, i / self.width))
+            .collect()
     }
 
-    pub fn swap(&mut self, x_1: usize, y_1: usize, x_2: usize, y_2: usize)
-    where
-        T: Copy,
-    {
-        if y_1 == y_2 {
-            self.data[y_1].swap(x_1, x_2);
-        } else {
-            let tmp = self.data[y_1][x_1];
-            self.data[y_1][x_1] = self.data[y_2][x_2];
-            self.data[y_2][x_2] = tmp;
-        }
+    pub fn swap(&mut self, x_1: usize, y_1: usize, x_2: usize, y_2: usize) {
+        let index_1 = self.index_of(x_1, y_1).expect("swap outside of the grid");
+        let index_2 = self.index_of(x_2, y_2).expect("swap outside of the grid");
+        self.data.swap(index_1, index_2);
     }
 
     pub fn get_hash(&self) -> u64
@@ -153,15 +278,9 @@ impl<T> Grid<T> {
     where
         F: Fn(&T) -> bool,
     {
-        let max_x = self.get_max_x();
-        let max_y = self.get_max_y();
-        for y in 0..max_y {
-            for x in 0..max_x {
-                if find_func(&self.data[y][x]) {
-                    return Some(GridPoint::new(x, y));
-                }
-            }
-        }
-        None
+        self.data
+            .iter()
+            .position(find_func)
+            .map(|i| GridPoint::new(i % self.width, i / self.width))
     }
 }
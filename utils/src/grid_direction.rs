@@ -68,4 +68,31 @@ impl GridDirection {
             GridDirection::Left => '<',
         }
     }
+
+    pub fn turn_left(&self) -> Self {
+        match self {
+            GridDirection::Up => GridDirection::Left,
+            GridDirection::Right => GridDirection::Up,
+            GridDirection::Down => GridDirection::Right,
+            GridDirection::Left => GridDirection::Down,
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            GridDirection::Up => GridDirection::Right,
+            GridDirection::Right => GridDirection::Down,
+            GridDirection::Down => GridDirection::Left,
+            GridDirection::Left => GridDirection::Up,
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        match self {
+            GridDirection::Up => GridDirection::Down,
+            GridDirection::Right => GridDirection::Left,
+            GridDirection::Down => GridDirection::Up,
+            GridDirection::Left => GridDirection::Right,
+        }
+    }
 }
@@ -46,7 +46,7 @@ impl<T> HashPointMap<T> {
         let mut max_x = i64::MIN;
         let mut max_y = i64::MIN;
 
-        for (map_point, _) in self.data.iter() {
+        for map_point in self.data.keys() {
             min_x = min_x.min(map_point.x);
             max_x = max_x.max(map_point.x);
             min_y = min_y.min(map_point.y);
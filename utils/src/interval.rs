@@ -0,0 +1,355 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, Mul, Sub},
+};
+
+pub trait Bound:
+    Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + From<u8>
+{
+}
+
+impl<T> Bound for T where
+    T: Copy + Ord + Debug + Add<Output = T> + Sub<Output = T> + Mul<Output = T> + From<u8>
+{
+}
+
+// Half-open interval `start..end`, empty when `end <= start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Bound> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    pub fn from_length(start: T, length: T) -> Self {
+        Self {
+            start,
+            end: start + length,
+        }
+    }
+
+    // Closed interval `start..=end`.
+    pub fn inclusive(start: T, end: T) -> Self {
+        Self {
+            start,
+            end: end + T::from(1),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::from(0)
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let rtn = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!rtn.is_empty()).then_some(rtn)
+    }
+
+    // The parts of `self` that are not in `other`, at most two.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+        [
+            Self::new(self.start, other.start),
+            Self::new(other.end, self.end),
+        ]
+        .into_iter()
+        .filter(|i| !i.is_empty())
+        .collect()
+    }
+
+    // Splits into the values below `value` and the values from `value` on.
+    pub fn split_at(&self, value: T) -> (Option<Self>, Option<Self>) {
+        let below = Self::new(self.start, self.end.min(value));
+        let above = Self::new(self.start.max(value), self.end);
+        (
+            (!below.is_empty()).then_some(below),
+            (!above.is_empty()).then_some(above),
+        )
+    }
+}
+
+// Sorted, disjoint and non-adjacent intervals.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Bound> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut intervals: Vec<Interval<T>> = iter.into_iter().filter(|i| !i.is_empty()).collect();
+        intervals.sort_unstable();
+
+        let mut rtn: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match rtn.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => rtn.push(interval),
+            }
+        }
+
+        Self { intervals: rtn }
+    }
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // Number of values in the set.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::from(0), |acc, i| acc + i.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(index).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        *self = self.iter().copied().chain([interval]).collect();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.iter().chain(other.iter()).copied().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut rtn = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            if let Some(overlap) = a.intersection(&b) {
+                rtn.push(overlap);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals: rtn }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut rtn = Vec::new();
+        for interval in self.intervals.iter() {
+            let mut remaining = vec![*interval];
+            for cut in other.intervals.iter().filter(|o| o.overlaps(interval)) {
+                remaining = remaining.iter().flat_map(|r| r.difference(cut)).collect();
+            }
+            rtn.append(&mut remaining);
+        }
+        Self { intervals: rtn }
+    }
+}
+
+// Piecewise shift: values inside a piece's source move to the same offset from its destination
+// start, every other value maps to itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalMap<T> {
+    // Sorted by source and disjoint.
+    pieces: Vec<(Interval<T>, T)>,
+}
+
+impl<T: Bound> IntervalMap<T> {
+    pub fn new() -> Self {
+        Self { pieces: Vec::new() }
+    }
+
+    pub fn pieces(&self) -> impl Iterator<Item = &(Interval<T>, T)> {
+        self.pieces.iter()
+    }
+
+    // Values that are already mapped keep their earlier mapping.
+    pub fn insert(&mut self, source: Interval<T>, destination_start: T) {
+        let taken: IntervalSet<T> = self.pieces.iter().map(|p| p.0).collect();
+        let free = IntervalSet::from_iter([source]).difference(&taken);
+        for part in free.iter() {
+            self.pieces
+                .push((*part, destination_start + (part.start - source.start)));
+        }
+        self.pieces.sort_unstable();
+    }
+
+    pub fn get(&self, value: T) -> T {
+        let index = self.pieces.partition_point(|p| p.0.end <= value);
+        match self.pieces.get(index) {
+            Some((source, destination)) if source.contains(value) => {
+                *destination + (value - source.start)
+            }
+            _ => value,
+        }
+    }
+
+    // Cuts `interval` along the pieces, every part with the value its start maps to.
+    fn split(&self, interval: &Interval<T>) -> Vec<(Interval<T>, T)> {
+        let mut rtn = Vec::new();
+        let mut current = interval.start;
+        let first = self.pieces.partition_point(|p| p.0.end <= interval.start);
+
+        for (source, destination) in self.pieces[first..].iter() {
+            if source.start >= interval.end {
+                break;
+            }
+            if current < source.start {
+                rtn.push((Interval::new(current, source.start), current));
+            }
+            let overlap = Interval::new(current.max(source.start), source.end.min(interval.end));
+            rtn.push((overlap, *destination + (overlap.start - source.start)));
+            current = overlap.end;
+        }
+        if current < interval.end {
+            rtn.push((Interval::new(current, interval.end), current));
+        }
+
+        rtn
+    }
+
+    pub fn map_interval(&self, interval: &Interval<T>) -> IntervalSet<T> {
+        if interval.is_empty() {
+            return IntervalSet::new();
+        }
+        self.split(interval)
+            .into_iter()
+            .map(|(part, start)| Interval::from_length(start, part.len()))
+            .collect()
+    }
+
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        set.iter()
+            .filter(|i| !i.is_empty())
+            .flat_map(|i| self.split(i))
+            .map(|(part, start)| Interval::from_length(start, part.len()))
+            .collect()
+    }
+
+    // All values that map into `set`.
+    pub fn preimage(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let sources: IntervalSet<T> = self.pieces.iter().map(|p| p.0).collect();
+        let mut rtn: Vec<Interval<T>> = set.difference(&sources).iter().copied().collect();
+
+        for (source, destination) in self.pieces.iter() {
+            let image = Interval::from_length(*destination, source.len());
+            for target in set.iter() {
+                if let Some(overlap) = image.intersection(target) {
+                    rtn.push(Interval::from_length(
+                        source.start + (overlap.start - *destination),
+                        overlap.len(),
+                    ));
+                }
+            }
+        }
+
+        rtn.into_iter().collect()
+    }
+
+    // A single map equal to applying `self` first and `next` afterwards.
+    pub fn compose(&self, next: &Self) -> Self {
+        let mut pieces = Vec::new();
+
+        for (source, destination) in self.pieces.iter() {
+            let image = Interval::from_length(*destination, source.len());
+            for (part, start) in next.split(&image) {
+                let part_source =
+                    Interval::from_length(source.start + (part.start - *destination), part.len());
+                pieces.push((part_source, start));
+            }
+        }
+
+        // Values untouched by `self` go straight into `next`.
+        let sources: IntervalSet<T> = self.pieces.iter().map(|p| p.0).collect();
+        for (source, destination) in next.pieces.iter() {
+            let free = IntervalSet::from_iter([*source]).difference(&sources);
+            for part in free.iter() {
+                pieces.push((*part, *destination + (part.start - source.start)));
+            }
+        }
+
+        // Identity pieces carry no information.
+        pieces.retain(|(source, destination)| source.start != *destination);
+        pieces.sort_unstable();
+
+        Self { pieces }
+    }
+}
+
+// Axis aligned box, one interval per dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HyperRect<T, const N: usize> {
+    pub axes: [Interval<T>; N],
+}
+
+impl<T: Bound, const N: usize> HyperRect<T, N> {
+    pub fn new(axes: [Interval<T>; N]) -> Self {
+        Self { axes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(|a| a.is_empty())
+    }
+
+    pub fn volume(&self) -> T {
+        self.axes.iter().fold(T::from(1), |acc, a| acc * a.len())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.axes;
+        for (axis, other_axis) in axes.iter_mut().zip(other.axes.iter()) {
+            *axis = axis.intersection(other_axis)?;
+        }
+        Some(Self { axes })
+    }
+
+    // Splits along `axis` into the box below `value` and the box from `value` on.
+    pub fn split_at(&self, axis: usize, value: T) -> (Option<Self>, Option<Self>) {
+        let (below, above) = self.axes[axis].split_at(value);
+        let with_axis = |interval: Interval<T>| {
+            let mut axes = self.axes;
+            axes[axis] = interval;
+            Self { axes }
+        };
+        (below.map(with_axis), above.map(with_axis))
+    }
+}
@@ -1,121 +1,151 @@
-#![feature(array_windows)]
 use std::{
     env::current_dir,
-    fs::File,
-    io::{BufRead, BufReader},
     path::{Path, PathBuf},
 };
 
+use parse_error::{parse_separated, parse_token, read_lines, ParseError, ParseResult};
+
+pub mod answers;
+pub mod bench;
+pub mod cycle;
+pub mod graph;
 pub mod grid;
 pub mod grid_direction;
 pub mod grid_point;
 pub mod grid_walker;
 pub mod hash_point_map;
+pub mod interval;
+pub mod linear;
+pub mod longest_path;
 pub mod map;
 pub mod map_direction;
+pub mod number;
+pub mod parse_error;
 pub mod point;
+pub mod rational;
+pub mod search;
+pub mod solution;
 pub mod utils;
 pub mod vector3;
 
-pub fn parse_file_into<T>(input_file: &str) -> Vec<T>
+pub fn try_parse_file_into<T>(input_file: &str) -> ParseResult<Vec<T>>
 where
     T: From<String>,
 {
-    let file = File::open(input_file).unwrap();
-    let reader = BufReader::new(file);
+    Ok(read_lines(input_file)?.into_iter().map(T::from).collect())
+}
 
-    reader
-        .lines()
-        .map(|l| l.unwrap().into())
-        .collect::<Vec<T>>()
+pub fn try_parse_into_char_vector_vector(input_file: &str) -> ParseResult<Vec<Vec<char>>> {
+    Ok(read_lines(input_file)?
+        .iter()
+        .map(|l| l.trim().chars().collect())
+        .collect())
 }
 
-pub fn parse_into_char_vector_vector(input_file: &str) -> Vec<Vec<char>> {
-    let file = File::open(input_file).unwrap();
-    let reader = BufReader::new(file);
+pub fn try_parse_into_i64_vector_vector(input_file: &str) -> ParseResult<Vec<Vec<i64>>> {
+    read_lines(input_file)?
+        .iter()
+        .enumerate()
+        .map(|(idx, l)| parse_separated(input_file, idx, l, ' ', "i64"))
+        .collect()
+}
 
-    let mut rtn = Vec::new();
+pub fn try_parse_into_usize_vector_vector(input_file: &str) -> ParseResult<Vec<Vec<usize>>> {
+    read_lines(input_file)?
+        .iter()
+        .enumerate()
+        .map(|(idx, l)| parse_separated(input_file, idx, l, ' ', "usize"))
+        .collect()
+}
 
-    for line in reader.lines() {
-        rtn.push(line.unwrap().trim().chars().collect())
-    }
+pub fn try_parse_into_usize_vector_vector_by_char(
+    input_file: &str,
+) -> ParseResult<Vec<Vec<usize>>> {
+    read_lines(input_file)?
+        .iter()
+        .enumerate()
+        .map(|(idx, l)| {
+            l.char_indices()
+                .map(|(column, c)| {
+                    c.to_digit(10)
+                        .map(|d| d as usize)
+                        .ok_or_else(|| ParseError::InvalidToken {
+                            path: PathBuf::from(input_file),
+                            line: idx + 1,
+                            column: column + 1,
+                            token: c.to_string(),
+                            expected: "digit",
+                        })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn try_parse_into_usize_vector(input_file: &str) -> ParseResult<Vec<usize>> {
+    read_lines(input_file)?
+        .iter()
+        .enumerate()
+        .map(|(idx, l)| parse_token(input_file, idx + 1, 1, l, "usize"))
+        .collect()
+}
+
+pub fn try_parse_into_i64_vector(input_file: &str) -> ParseResult<Vec<i64>> {
+    read_lines(input_file)?
+        .iter()
+        .enumerate()
+        .map(|(idx, l)| parse_token(input_file, idx + 1, 1, l, "i64"))
+        .collect()
+}
+
+fn or_panic<T>(result: ParseResult<T>) -> T {
+    result.unwrap_or_else(|e| panic!("{}", e))
+}
+
+pub fn parse_file_into<T>(input_file: &str) -> Vec<T>
+where
+    T: From<String>,
+{
+    or_panic(try_parse_file_into(input_file))
+}
 
-    rtn
+pub fn parse_into_char_vector_vector(input_file: &str) -> Vec<Vec<char>> {
+    or_panic(try_parse_into_char_vector_vector(input_file))
 }
 
 pub fn parse_into_i64_vector_vector(input_file: &str) -> Vec<Vec<i64>> {
-    let file = File::open(input_file).unwrap();
-    let reader = BufReader::new(file);
-
-    reader
-        .lines()
-        .map(|l| {
-            l.unwrap()
-                .split(' ')
-                .into_iter()
-                .map(|n| n.parse::<i64>().unwrap())
-                .collect::<Vec<i64>>()
-        })
-        .collect::<Vec<Vec<i64>>>()
+    or_panic(try_parse_into_i64_vector_vector(input_file))
 }
 
 pub fn parse_into_usize_vector_vector(input_file: &str) -> Vec<Vec<usize>> {
-    let file = File::open(input_file).unwrap();
-    let reader = BufReader::new(file);
-
-    reader
-        .lines()
-        .map(|l| {
-            l.unwrap()
-                .split(' ')
-                .into_iter()
-                .map(|n| n.parse::<usize>().unwrap())
-                .collect::<Vec<usize>>()
-        })
-        .collect::<Vec<Vec<usize>>>()
+    or_panic(try_parse_into_usize_vector_vector(input_file))
 }
 
 pub fn parse_into_usize_vector_vector_by_char(input_file: &str) -> Vec<Vec<usize>> {
-    let file = File::open(input_file).unwrap();
-    let reader = BufReader::new(file);
-
-    reader
-        .lines()
-        .map(|l| {
-            l.unwrap()
-                .chars()
-                .map(|n| n.to_digit(10).unwrap() as usize)
-                .collect::<Vec<usize>>()
-        })
-        .collect::<Vec<Vec<usize>>>()
+    or_panic(try_parse_into_usize_vector_vector_by_char(input_file))
 }
 
 pub fn parse_into_usize_vector(input_file: &str) -> Vec<usize> {
-    let file = File::open(input_file).unwrap();
-    let reader = BufReader::new(file);
-
-    reader
-        .lines()
-        .map(|l| {
-            l.unwrap()
-                .parse::<usize>()
-                .expect("Could not parse \"{l}\" into usize")
-        })
-        .collect::<Vec<usize>>()
+    or_panic(try_parse_into_usize_vector(input_file))
 }
 
 pub fn parse_into_i64_vector(input_file: &str) -> Vec<i64> {
-    let file = File::open(input_file).unwrap();
-    let reader = BufReader::new(file);
-
-    reader
-        .lines()
-        .map(|l| {
-            l.unwrap()
-                .parse::<i64>()
-                .expect("Could not parse \"{l}\" into i64")
-        })
-        .collect::<Vec<i64>>()
+    or_panic(try_parse_into_i64_vector(input_file))
+}
+
+pub fn get_input_file_path(src_path: &str, file_name: &str) -> PathBuf {
+    let file_path = Path::new(src_path);
+    if Path::exists(file_path) {
+        file_path
+            .parent()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .join("input")
+            .join(file_name)
+    } else {
+        current_dir().unwrap().join("input").join(file_name)
+    }
 }
 
 pub fn get_input_path(src_path: &str) -> PathBuf {
@@ -166,30 +196,38 @@ pub fn get_test_input_2_path(src_path: &str) -> PathBuf {
     }
 }
 
-fn gcd(a: usize, b: usize) -> usize {
-    match ((a, b), (a & 1, b & 1)) {
-        ((x, y), _) if x == y => y,
-        ((0, x), _) | ((x, 0), _) => x,
-        ((x, y), (0, 1)) | ((y, x), (1, 0)) => gcd(x >> 1, y),
-        ((x, y), (0, 0)) => gcd(x >> 1, y >> 1) << 1,
-        ((x, y), (1, 1)) => {
-            let (x, y) = (x.min(y), x.max(y));
-            gcd((y - x) >> 1, x)
-        }
-        _ => unreachable!(),
-    }
-}
-
-pub fn lcm(a: usize, b: usize) -> usize {
-    a * b / gcd(a, b)
+// Writes `content` to `name` in the temp directory, for tests that need their own input file.
+pub fn write_temp_input(name: &str, content: &str) -> String {
+    let path = std::env::temp_dir().join(name);
+    std::fs::write(&path, content).unwrap();
+    path.to_str().unwrap().to_string()
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
+    use crate::answers::Answers;
+    use crate::bench::Stats;
+    use crate::cycle::{detect_cycle, detect_cycle_brent, Repeat};
     use crate::get_input_path;
     use crate::get_test_input_path;
+    use crate::graph::{Edge, EdgeKind, Graph};
+    use crate::grid::{Grid, GridError};
+    use crate::grid_point::GridPoint;
+    use crate::interval::{HyperRect, Interval, IntervalMap, IntervalSet};
+    use crate::linear::{solve, solve_integer, LinearError};
+    use crate::longest_path::{longest_path, SearchOptions};
     use crate::map::Map;
-    use crate::map::Point;
+    use crate::number::{
+        checked_gcd, checked_lcm, crt, extended_gcd, gcd, lcm, mod_inverse, mod_pow, mul_mod,
+    };
+    use crate::parse_error::ParseError;
+    use crate::point::MapPoint;
+    use crate::rational::Rational;
+    use crate::search::{astar, bfs, dijkstra, dijkstra_distances};
+    use crate::write_temp_input;
+    use crate::{try_parse_into_i64_vector_vector, try_parse_into_usize_vector_vector_by_char};
 
     #[test]
     fn test_get_test_input_path() {
@@ -206,16 +244,453 @@ mod tests {
         let mut map = Map::new(10, 10);
         map.set(5, 5, 10);
         let rtn = map.get(5, 5);
-        let rtn2 = map.get_from_point(Point { x: 5, y: 5 });
+        let rtn2 = map.get_from_point(&MapPoint { x: 5, y: 5 });
         assert!(rtn == 10);
         assert!(rtn2 == 10);
     }
 
     #[test]
     fn test_point() {
-        let p1 = Point { x: 0, y: 0 };
-        let p2 = Point { x: 5, y: 5 };
-        let distance = p1.manhatten_distance(p2);
+        let p1 = MapPoint { x: 0, y: 0 };
+        let p2 = MapPoint { x: 5, y: 5 };
+        let distance = p1.manhatten_distance(&p2);
         assert!(distance == 10);
     }
+
+    #[test]
+    fn test_parse_error_location() {
+        let input_file = write_temp_input("utils_parse_error_location.txt", "1 2 3\n4 x5 6\n");
+        match try_parse_into_i64_vector_vector(&input_file) {
+            Err(ParseError::InvalidToken {
+                line,
+                column,
+                token,
+                ..
+            }) => {
+                assert_eq!(line, 2);
+                assert_eq!(column, 3);
+                assert_eq!(token, "x5");
+            }
+            other => panic!("Unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_error_digit() {
+        let input_file = write_temp_input("utils_parse_error_digit.txt", "123\n45a\n");
+        let error = try_parse_into_usize_vector_vector_by_char(&input_file).unwrap_err();
+        assert!(error
+            .to_string()
+            .ends_with(":2:3: could not parse \"a\" into digit"));
+    }
+
+    #[test]
+    fn test_parse_error_missing_file() {
+        let error = try_parse_into_i64_vector_vector("does/not/exist.txt").unwrap_err();
+        assert!(matches!(error, ParseError::Io { .. }));
+    }
+
+    #[test]
+    fn test_grid_rejects_ragged_rows() {
+        let result = Grid::try_from(vec![vec![1, 2, 3], vec![4, 5]]);
+        assert_eq!(
+            result,
+            Err(GridError::RaggedRow {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_grid_views() {
+        let mut grid: Grid<usize> = vec![vec![1, 2, 3], vec![4, 5, 6]].try_into().unwrap();
+        assert_eq!(grid.get_max_x(), 3);
+        assert_eq!(grid.get_max_y(), 2);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.find(|i| *i == 5), Some(GridPoint::new(1, 1)));
+
+        grid.insert_column(1, 0);
+        grid.insert_row(0, vec![7, 7, 7, 7]);
+        assert_eq!(grid.grid_to_string(), "7777\n1023\n4056\n");
+    }
+
+    #[test]
+    fn test_bfs_path() {
+        let result = bfs([1usize], |n| [n + 1, n * 3], |n| *n == 10).unwrap();
+        assert_eq!(result.cost, 3);
+        assert_eq!(result.path, vec![1, 3, 9, 10]);
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // Walking costs 1, jumping two ahead costs 3.
+        let successors = |n: &i64| [(n + 1, 1), (n + 2, 3)];
+        let result = dijkstra([0i64], successors, |n| *n == 5).unwrap();
+        assert_eq!(result.cost, 5);
+        assert_eq!(result.path, vec![0, 1, 2, 3, 4, 5]);
+
+        let guided = astar([0i64], successors, |n| 5 - n, |n| *n == 5).unwrap();
+        assert_eq!(guided.cost, 5);
+        assert!(guided.visited <= result.visited);
+    }
+
+    #[test]
+    fn test_cycle_detection() {
+        // 3 -> 10 -> 5 -> 16 -> 8 -> 4 -> 2 -> 1 -> 4 -> ...
+        let collatz = |n: &u64| {
+            if n.is_multiple_of(2) {
+                n / 2
+            } else {
+                3 * n + 1
+            }
+        };
+        for cycle in [
+            detect_cycle(3, collatz, 1_000_000_000),
+            detect_cycle_brent(3, collatz, 1_000_000_000),
+        ] {
+            assert_eq!(
+                cycle.repeat,
+                Some(Repeat {
+                    prefix: 5,
+                    period: 3
+                })
+            );
+            // (1_000_000_000 - 5) % 3 == 2 steps into the cycle 4 -> 2 -> 1
+            assert_eq!(cycle.state, 1);
+        }
+        assert_eq!(detect_cycle(3, collatz, 2).state, 5);
+        assert_eq!(detect_cycle_brent(3, collatz, 2).state, 5);
+    }
+
+    #[test]
+    fn test_cycle_detection_without_repeat() {
+        for cycle in [
+            detect_cycle(0u64, |n| n + 1, 1000),
+            detect_cycle_brent(0u64, |n| n + 1, 1000),
+        ] {
+            assert_eq!(cycle.state, 1000);
+            assert_eq!(cycle.repeat, None);
+        }
+        assert_eq!(detect_cycle(0u64, |n| n + 1, 0).state, 0);
+        assert_eq!(detect_cycle_brent(0u64, |n| n + 1, 0).state, 0);
+    }
+
+    #[test]
+    fn test_answers_parse() {
+        let content =
+            "# comment\n[input_test]\npart_1 = 142\n\n[input_test_2]\npart_2 = \"281\"\nsteps = 6\n";
+        let answers = Answers::parse("answers.toml", content).unwrap();
+        assert_eq!(answers.setting("input_test_2", "steps"), Some("6"));
+        assert_eq!(answers.setting("input_test", "steps"), None);
+        assert_eq!(answers.expected("input_test", 1), Some("142"));
+        assert_eq!(answers.expected("input_test_2", 2), Some("281"));
+        assert_eq!(answers.expected("input_test", 2), None);
+        assert!(Answers::parse("answers.toml", "[input\n").is_err());
+    }
+
+    #[test]
+    fn test_bench_stats() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples, 3);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(10));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+        assert_eq!(stats.allocations, 3);
+    }
+
+    #[test]
+    fn test_interval_set() {
+        let set: IntervalSet<i64> = [
+            Interval::new(5, 10),
+            Interval::new(0, 3),
+            Interval::new(3, 4),
+            Interval::new(8, 12),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            vec![Interval::new(0, 4), Interval::new(5, 12)]
+        );
+        assert_eq!(set.len(), 11);
+        assert!(set.contains(11) && !set.contains(4));
+
+        let cut = IntervalSet::from_iter([Interval::new(2, 6)]);
+        assert_eq!(set.difference(&cut).len(), 8);
+        assert_eq!(set.intersection(&cut).len(), 3);
+    }
+
+    #[test]
+    fn test_interval_map_compose() {
+        let mut first = IntervalMap::new();
+        first.insert(Interval::from_length(98usize, 2), 50);
+        first.insert(Interval::from_length(50, 48), 52);
+        let mut second = IntervalMap::new();
+        second.insert(Interval::from_length(15usize, 37), 0);
+        second.insert(Interval::from_length(52, 2), 37);
+        second.insert(Interval::from_length(0, 15), 39);
+
+        let composed = first.compose(&second);
+        for value in 0..120 {
+            assert_eq!(composed.get(value), second.get(first.get(value)));
+        }
+
+        let seeds = IntervalSet::from_iter([Interval::new(40, 60)]);
+        let mapped = composed.map_set(&seeds);
+        let expected: IntervalSet<usize> = (40..60)
+            .map(|v| Interval::from_length(composed.get(v), 1))
+            .collect();
+        assert_eq!(mapped, expected);
+        assert_eq!(composed.preimage(&mapped).intersection(&seeds), seeds);
+    }
+
+    #[test]
+    fn test_hyper_rect() {
+        let rect = HyperRect::new([Interval::inclusive(1usize, 4000); 4]);
+        assert_eq!(rect.volume(), 4000usize.pow(4));
+
+        let (below, above) = rect.split_at(1, 1001);
+        assert_eq!(below.unwrap().volume(), 1000 * 4000usize.pow(3));
+        assert_eq!(above.unwrap().volume(), 3000 * 4000usize.pow(3));
+        assert_eq!(rect.split_at(0, 1).0, None);
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12u8, 18), 6);
+        assert_eq!(gcd(-12i64, 18), 6);
+        assert_eq!(gcd(0usize, 0), 0);
+        assert_eq!(lcm(4u32, 6), 12);
+        assert_eq!(lcm(-4i32, 6), 12);
+        assert_eq!(checked_lcm(200u8, 3), None);
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+
+        // |i64::MIN| does not fit into i64.
+        assert_eq!(gcd(i64::MIN, 6), 2);
+        assert_eq!(gcd(i64::MIN, -1), 1);
+        assert_eq!(checked_gcd(i64::MIN, 0), None);
+        assert_eq!(checked_lcm(i64::MIN, 3), None);
+        assert_eq!(checked_lcm(i64::MIN, 2), None);
+    }
+
+    #[test]
+    fn test_modular_arithmetic() {
+        let (g, x, y) = extended_gcd(240i64, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(mod_inverse(3i32, 11), Some(4));
+        assert_eq!(mod_inverse(4i32, 8), None);
+        assert_eq!(mod_pow(4u64, 13, 497), 445);
+        assert_eq!(mul_mod(u64::MAX - 1, u64::MAX - 1, u64::MAX), 1);
+        assert_eq!(mod_pow(2i128, 127, i128::MAX), 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2i64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Not coprime, but consistent.
+        assert_eq!(crt(&[(3i64, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt(&[(1i64, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(0i64, 5), (-1, 7)]), Some((20, 35)));
+        assert_eq!(crt::<i32>(&[]), Some((0, 1)));
+
+        let large = 1_000_000_000_000_000_003i128;
+        assert_eq!(
+            crt(&[(large - 1, large), (0, 2)]),
+            Some((large - 1, 2 * large))
+        );
+        assert_eq!(
+            crt(&[(1, large), (0, large + 1)]),
+            Some((large + 1, large * (large + 1)))
+        );
+
+        // The lcm of both moduli does not fit into i64.
+        assert_eq!(crt(&[(1i64, i64::MAX), (0, i64::MAX - 1)]), None);
+    }
+
+    #[test]
+    fn test_dijkstra_distances() {
+        // 0 -> 1 costs 5, 0 -> 2 -> 1 only 3, 3 is unreachable.
+        let edges: [&[(usize, usize)]; 4] = [&[(1, 5), (2, 1)], &[], &[(1, 2)], &[(0, 1)]];
+        let distances = dijkstra_distances([0], |s: &usize| edges[*s].iter().copied());
+        assert_eq!(distances.len(), 3);
+        assert_eq!(distances[&0], 0);
+        assert_eq!(distances[&1], 3);
+        assert_eq!(distances[&2], 1);
+    }
+
+    #[test]
+    fn test_rational() {
+        let half = Rational::new(1, 2);
+        let third = Rational::new(-2, -6);
+        assert_eq!(third, Rational::new(1, 3));
+        assert_eq!(Rational::new(4, -6), Rational::new(-2, 3));
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(half / third, Rational::new(3, 2));
+        assert_eq!(-half + half, Rational::ZERO);
+        assert!(Rational::new(-1, 2) < third && third < half);
+
+        // Cross multiplying these overflows.
+        let max = i128::MAX;
+        assert!(Rational::new(max - 2, 2) < Rational::new(max, 2));
+        assert!(Rational::new(max, max - 1) < Rational::new(max - 1, max - 2));
+        assert!(Rational::new(-max, 3) < Rational::new(-max + 3, 3));
+        assert!(Rational::new(max, 7) > Rational::new(max - 1, 7));
+        assert_eq!(
+            Rational::new(max, 3).cmp(&Rational::new(max, 3)),
+            std::cmp::Ordering::Equal
+        );
+        assert_eq!((half * Rational::from(4)).to_integer(), Some(2));
+        assert_eq!(half.to_integer(), None);
+        assert_eq!(Rational::new(-3, 9).to_string(), "-1/3");
+
+        // Would overflow without reducing before multiplying.
+        let big = Rational::new(1, 1 << 100);
+        assert_eq!(big * Rational::from(1 << 100), Rational::ONE);
+        assert_eq!(Rational::from(i128::MAX).checked_add(Rational::ONE), None);
+        assert_eq!(Rational::ONE.checked_div(Rational::ZERO), None);
+    }
+
+    #[test]
+    fn test_linear_systems() {
+        let matrix = vec![vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]];
+        let rhs = [8, -11, -3];
+        let expected = [Rational::from(2), Rational::from(3), Rational::from(-1)];
+        assert_eq!(solve_integer(&matrix, &rhs), Ok(expected.to_vec()));
+
+        let rational = |rows: &[Vec<i128>]| -> Vec<Vec<Rational>> {
+            rows.iter()
+                .map(|row| row.iter().map(|c| Rational::from(*c)).collect())
+                .collect()
+        };
+        let rational_rhs = rhs.map(Rational::from);
+        assert_eq!(
+            solve(&rational(&matrix), &rational_rhs),
+            Ok(expected.to_vec())
+        );
+
+        // Needs a row swap and has a fractional solution.
+        let matrix = vec![vec![0, 2], vec![3, 0]];
+        let expected = vec![Rational::new(1, 3), Rational::new(1, 2)];
+        assert_eq!(solve_integer(&matrix, &[1, 1]), Ok(expected.clone()));
+        let ones = [Rational::ONE; 2];
+        assert_eq!(solve(&rational(&matrix), &ones), Ok(expected));
+
+        let dependent = vec![vec![1, 2], vec![2, 4]];
+        assert_eq!(
+            solve_integer(&dependent, &[3, 6]),
+            Err(LinearError::Singular)
+        );
+        assert_eq!(
+            solve_integer(&dependent, &[3, 7]),
+            Err(LinearError::Inconsistent)
+        );
+        let six = [Rational::from(3), Rational::from(6)];
+        assert_eq!(
+            solve(&rational(&dependent), &six),
+            Err(LinearError::Singular)
+        );
+        assert_eq!(
+            solve_integer(&[vec![1, 2]], &[1]),
+            Err(LinearError::Dimensions)
+        );
+        assert_eq!(solve_integer(&[], &[]), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_graph() {
+        let graph = Graph::from_adjacency("a: b c\nd: e\nc: a\n", EdgeKind::Undirected).unwrap();
+        assert_eq!(graph.names(), ["a", "b", "c", "d", "e"]);
+        assert_eq!(graph.id("c"), Some(2));
+        assert_eq!(graph.name(3), "d");
+        assert_eq!(graph.edges().len(), 4);
+        assert_eq!(graph.degree(0), 3);
+        assert_eq!(graph.degree(1), 1);
+        assert_eq!(graph.neighbours(2).collect::<Vec<_>>(), [(0, &1), (0, &1)]);
+        assert_eq!(graph.components(), [vec![0, 1, 2], vec![3, 4]]);
+
+        let graph = Graph::from_adjacency("x -> y, z\nz -> x\n", EdgeKind::Directed).unwrap();
+        assert_eq!(graph.degree(0), 2);
+        assert_eq!(graph.in_degree(0), 1);
+        assert_eq!(graph.degree(1), 0);
+        assert_eq!(graph.neighbours(2).collect::<Vec<_>>(), [(0, &1)]);
+        assert_eq!(graph.components(), [vec![0, 1, 2]]);
+        assert_eq!(
+            graph.to_dot(),
+            "digraph {\n    \"x\";\n    \"y\";\n    \"z\";\n    \"x\" -> \"y\" [label=\"1\"];\n    \"x\" -> \"z\" [label=\"1\"];\n    \"z\" -> \"x\" [label=\"1\"];\n}\n"
+        );
+
+        assert!(Graph::from_adjacency("a b", EdgeKind::Directed).is_err());
+        assert!(Graph::from_adjacency("a -> b,", EdgeKind::Directed).is_err());
+
+        let mut weighted: Graph<f64> = Graph::new(EdgeKind::Undirected);
+        weighted.add_edge("p", "q", 2.5);
+        weighted.add_edge("q", "q", 1.0);
+        assert_eq!(weighted.degree(1), 2);
+        assert_eq!(
+            weighted.edges()[0],
+            Edge {
+                from: 0,
+                to: 1,
+                weight: 2.5
+            }
+        );
+        assert!(weighted
+            .to_dot()
+            .contains("\"p\" -- \"q\" [label=\"2.5\"];"));
+    }
+
+    #[test]
+    fn test_longest_path() {
+        let undirected = |nodes: usize, edges: &[(usize, usize, usize)]| {
+            let mut graph = Graph::new(EdgeKind::Undirected);
+            (0..nodes).for_each(|n| {
+                graph.intern(&n.to_string());
+            });
+            for &(a, b, weight) in edges {
+                graph.add_edge_by_id(a, b, weight);
+            }
+            graph
+        };
+
+        // The detour 0 - 1 - 2 - 3 - 4 beats every shortcut.
+        let graph = undirected(
+            6,
+            &[
+                (0, 1, 1),
+                (1, 2, 10),
+                (1, 3, 1),
+                (2, 3, 10),
+                (3, 4, 1),
+                (2, 4, 1),
+                (4, 5, 2),
+            ],
+        );
+        let sequential = SearchOptions::default();
+        let parallel = SearchOptions {
+            threads: 4,
+            split_depth: 2,
+        };
+        for options in [sequential, parallel] {
+            assert_eq!(longest_path(&graph, 0, 5, &options), Some(24));
+            assert_eq!(longest_path(&graph, 5, 0, &options), Some(24));
+            assert_eq!(longest_path(&graph, 2, 2, &options), Some(0));
+        }
+
+        let disconnected = undirected(3, &[(0, 1, 1)]);
+        assert_eq!(longest_path(&disconnected, 0, 2, &sequential), None);
+
+        // Only the long way round follows the directions.
+        let directed =
+            Graph::from_adjacency("a -> b, c\nb -> d\nc -> b\n", EdgeKind::Directed).unwrap();
+        assert_eq!(longest_path(&directed, 0, 3, &sequential), Some(3));
+        assert_eq!(longest_path(&directed, 3, 0, &sequential), None);
+    }
 }
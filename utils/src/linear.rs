@@ -0,0 +1,158 @@
+use std::{error::Error, fmt};
+
+use crate::{number::gcd, rational::Rational};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinearError {
+    // The matrix is not square or does not match the right hand side.
+    Dimensions,
+    // Infinitely many solutions.
+    Singular,
+    // No solution at all.
+    Inconsistent,
+    // An intermediate value does not fit into `i128`.
+    Overflow,
+}
+
+impl fmt::Display for LinearError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinearError::Dimensions => write!(f, "matrix and right hand side do not fit"),
+            LinearError::Singular => write!(f, "system has infinitely many solutions"),
+            LinearError::Inconsistent => write!(f, "system has no solution"),
+            LinearError::Overflow => write!(f, "solving the system overflows"),
+        }
+    }
+}
+
+impl Error for LinearError {}
+
+// Matrix with the right hand side as last column.
+fn augmented<T: Copy>(matrix: &[Vec<T>], rhs: &[T]) -> Result<Vec<Vec<T>>, LinearError> {
+    if matrix.len() != rhs.len() || matrix.iter().any(|row| row.len() != rhs.len()) {
+        return Err(LinearError::Dimensions);
+    }
+    Ok(matrix
+        .iter()
+        .zip(rhs.iter())
+        .map(|(row, value)| row.iter().copied().chain([*value]).collect())
+        .collect())
+}
+
+// Checks the rows below `rank` of a matrix in row echelon form.
+fn check_rank<T: PartialEq>(rows: &[Vec<T>], rank: usize, zero: T) -> Result<(), LinearError> {
+    let n = rows.len();
+    if rows[rank..].iter().any(|row| row[n] != zero) {
+        Err(LinearError::Inconsistent)
+    } else if rank < n {
+        Err(LinearError::Singular)
+    } else {
+        Ok(())
+    }
+}
+
+// Solves `matrix * x = rhs` exactly for a square `matrix`.
+#[allow(clippy::needless_range_loop)]
+pub fn solve(matrix: &[Vec<Rational>], rhs: &[Rational]) -> Result<Vec<Rational>, LinearError> {
+    let mut rows = augmented(matrix, rhs)?;
+    let n = rows.len();
+
+    // Gauss-Jordan, every pivot ends up as the only non zero entry of its column.
+    let mut rank = 0;
+    for column in 0..n {
+        let Some(index) = (rank..n).find(|r| !rows[*r][column].is_zero()) else {
+            continue;
+        };
+        rows.swap(rank, index);
+
+        let pivot = rows[rank][column];
+        for col in column..=n {
+            rows[rank][col] = rows[rank][col]
+                .checked_div(pivot)
+                .ok_or(LinearError::Overflow)?;
+        }
+        for row in 0..n {
+            let factor = rows[row][column];
+            if row != rank && !factor.is_zero() {
+                for col in column..=n {
+                    rows[row][col] = factor
+                        .checked_mul(rows[rank][col])
+                        .and_then(|value| rows[row][col].checked_sub(value))
+                        .ok_or(LinearError::Overflow)?;
+                }
+            }
+        }
+        rank += 1;
+    }
+
+    check_rank(&rows, rank, Rational::ZERO)?;
+    Ok(rows.iter().map(|row| row[n]).collect())
+}
+
+// Like `solve`, but the elimination stays in integers. Rows are only ever replaced by their
+// difference to a multiple of another row and divided by their gcd, like the Euclidean
+// algorithm, which keeps the coefficients far smaller than cross multiplying rows would.
+#[allow(clippy::needless_range_loop)]
+pub fn solve_integer(matrix: &[Vec<i128>], rhs: &[i128]) -> Result<Vec<Rational>, LinearError> {
+    let mut rows = augmented(matrix, rhs)?;
+    let n = rows.len();
+
+    // Forward elimination into row echelon form.
+    let mut rank = 0;
+    for column in 0..n {
+        // Positive leading coefficients keep every remainder below non negative.
+        for row in &mut rows[rank..] {
+            if row[column] < 0 {
+                row.iter_mut().for_each(|c| *c = -*c);
+            }
+        }
+
+        loop {
+            for row in &mut rows[rank..] {
+                let factor = row[column..].iter().fold(0, |acc, c| gcd(acc, *c));
+                if factor > 1 {
+                    row[column..].iter_mut().for_each(|c| *c /= factor);
+                }
+            }
+
+            let non_zero: Vec<usize> = (rank..n).filter(|r| rows[*r][column] != 0).collect();
+            match non_zero[..] {
+                [] => break,
+                [index] => {
+                    rows.swap(rank, index);
+                    rank += 1;
+                    break;
+                }
+                _ => {}
+            }
+
+            // Shrink the column towards zero with the row that has the smallest coefficient.
+            let index = *non_zero.iter().min_by_key(|r| rows[**r][column]).unwrap();
+            for row in non_zero {
+                if row != index {
+                    let factor = rows[row][column] / rows[index][column];
+                    for col in column..=n {
+                        rows[row][col] = factor
+                            .checked_mul(rows[index][col])
+                            .and_then(|value| rows[row][col].checked_sub(value))
+                            .ok_or(LinearError::Overflow)?;
+                    }
+                }
+            }
+        }
+    }
+
+    check_rank(&rows, rank, 0)?;
+
+    // Back substitution, the matrix is upper triangular now.
+    let mut rtn = vec![Rational::ZERO; n];
+    for pivot in (0..n).rev() {
+        let known = (pivot + 1..n).try_fold(Rational::from(rows[pivot][n]), |acc, col| {
+            acc.checked_sub(Rational::from(rows[pivot][col]).checked_mul(rtn[col])?)
+        });
+        rtn[pivot] = known
+            .and_then(|known| known.checked_div(Rational::from(rows[pivot][pivot])))
+            .ok_or(LinearError::Overflow)?;
+    }
+    Ok(rtn)
+}
@@ -0,0 +1,171 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+use crate::graph::{Graph, NodeId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchOptions {
+    // 1 searches on the calling thread only.
+    pub threads: usize,
+    // Depth up to which paths are expanded before they are shared out between threads.
+    pub split_depth: usize,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            threads: 1,
+            split_depth: 6,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Partial {
+    node: usize,
+    visited: u64,
+    length: usize,
+    // Sum of `best_edge` over every node that is neither visited nor the goal.
+    remaining: usize,
+}
+
+struct Search {
+    // Neighbour and weight of every edge leaving each node.
+    adjacency: Vec<Vec<(usize, usize)>>,
+    goal: usize,
+    // The only node with an edge into the goal, it has to take that edge.
+    gate: Option<usize>,
+    best_edge: Vec<usize>,
+    // Longest length found plus one, zero while the goal was not reached.
+    best: AtomicUsize,
+}
+
+impl Search {
+    fn record(&self, length: usize) {
+        self.best.fetch_max(length + 1, Ordering::Relaxed);
+    }
+
+    // Every edge still to come leaves `node` or a different unvisited node.
+    fn is_hopeless(&self, partial: &Partial) -> bool {
+        let bound = partial.length + self.best_edge[partial.node] + partial.remaining;
+        bound < self.best.load(Ordering::Relaxed)
+    }
+
+    fn next(&self, partial: &Partial) -> impl Iterator<Item = Partial> + '_ {
+        let partial = *partial;
+        self.adjacency[partial.node]
+            .iter()
+            .filter(move |(next, _)| {
+                partial.visited & (1 << next) == 0
+                    && (self.gate != Some(partial.node) || *next == self.goal)
+            })
+            .map(move |&(next, weight)| Partial {
+                node: next,
+                visited: partial.visited | (1 << next),
+                length: partial.length + weight,
+                remaining: if next == self.goal {
+                    partial.remaining
+                } else {
+                    partial.remaining - self.best_edge[next]
+                },
+            })
+    }
+
+    fn dfs(&self, partial: Partial) {
+        if partial.node == self.goal {
+            self.record(partial.length);
+            return;
+        }
+        if self.is_hopeless(&partial) {
+            return;
+        }
+        for next in self.next(&partial) {
+            self.dfs(next);
+        }
+    }
+
+    // Paths of `depth` edges, or shorter ones that already reached the goal.
+    fn expand(&self, start: Partial, depth: usize) -> Vec<Partial> {
+        let mut rtn = vec![start];
+        for _ in 0..depth {
+            rtn = rtn
+                .iter()
+                .flat_map(|p| -> Vec<Partial> {
+                    if p.node == self.goal {
+                        vec![*p]
+                    } else {
+                        self.next(p).collect()
+                    }
+                })
+                .collect();
+        }
+        rtn
+    }
+}
+
+// Length of the longest path from `start` to `goal` that visits no node twice, the edge weights
+// are the lengths. Exponential, so the graph has to be compressed to at most 64 nodes first.
+pub fn longest_path(
+    graph: &Graph<usize>,
+    start: NodeId,
+    goal: NodeId,
+    options: &SearchOptions,
+) -> Option<usize> {
+    assert!(
+        graph.len() <= 64,
+        "Only up to 64 nodes fit into the visited mask"
+    );
+
+    let adjacency: Vec<Vec<(usize, usize)>> = (0..graph.len())
+        .map(|n| graph.neighbours(n).map(|(next, w)| (next, *w)).collect())
+        .collect();
+    let best_edge: Vec<usize> = adjacency
+        .iter()
+        .map(|edges| edges.iter().map(|e| e.1).max().unwrap_or(0))
+        .collect();
+    let into_goal: Vec<usize> = (0..graph.len())
+        .filter(|n| adjacency[*n].iter().any(|e| e.0 == goal))
+        .collect();
+
+    let search = Search {
+        adjacency,
+        goal,
+        gate: (into_goal.len() == 1).then(|| into_goal[0]),
+        best_edge: best_edge.clone(),
+        best: AtomicUsize::new(0),
+    };
+    let remaining = (0..graph.len())
+        .filter(|n| *n != start && *n != goal)
+        .map(|n| best_edge[n])
+        .sum();
+    let start = Partial {
+        node: start,
+        visited: 1 << start,
+        length: 0,
+        remaining,
+    };
+
+    if options.threads <= 1 {
+        search.dfs(start);
+    } else {
+        let queue = Mutex::new(search.expand(start, options.split_depth));
+        thread::scope(|scope| {
+            for _ in 0..options.threads {
+                scope.spawn(|| loop {
+                    let next = queue.lock().unwrap().pop();
+                    let Some(partial) = next else {
+                        break;
+                    };
+                    search.dfs(partial);
+                });
+            }
+        });
+    }
+
+    search.best.load(Ordering::Relaxed).checked_sub(1)
+}
@@ -47,7 +47,7 @@ impl Map {
     pub fn get(&self, x: i64, y: i64) -> i64 {
         let mut rtn = y * self.range_y - self.min_y;
         rtn += x - self.min_x;
-        return *self.data.get(rtn as usize).unwrap();
+        *self.data.get(rtn as usize).unwrap()
     }
 
     pub fn set(&mut self, x: i64, y: i64, value: i64) {
@@ -0,0 +1,197 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+}
+
+// Integers that extended Euclid and the modular functions built on it work with.
+pub trait SignedInteger: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn checked_rem(self, other: Self) -> Option<Self> {
+                    <$t>::checked_rem(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl SignedInteger for i8 {}
+impl SignedInteger for i16 {}
+impl SignedInteger for i32 {}
+impl SignedInteger for i64 {}
+impl SignedInteger for i128 {}
+impl SignedInteger for isize {}
+
+// None for the minimum of a signed type.
+fn checked_abs<T: Integer>(value: T) -> Option<T> {
+    if value < T::ZERO {
+        T::ZERO.checked_sub(value)
+    } else {
+        Some(value)
+    }
+}
+
+// Always non negative, `gcd(0, 0)` is 0. None if the result does not fit into `T`, which only
+// happens for the minimum of a signed type and a multiple of it.
+pub fn checked_gcd<T: Integer>(mut a: T, mut b: T) -> Option<T> {
+    while b != T::ZERO {
+        // Only the minimum modulo -1 fails, and that remainder is 0.
+        (a, b) = (b, a.checked_rem(b).unwrap_or(T::ZERO));
+    }
+    checked_abs(a)
+}
+
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    checked_gcd(a, b).unwrap_or_else(|| panic!("gcd of {:?} and {:?} overflows", a, b))
+}
+
+// None if the result does not fit into `T`.
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (checked_abs(a)? / checked_gcd(a, b)?).checked_mul(checked_abs(b)?)
+}
+
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).unwrap_or_else(|| panic!("lcm of {:?} and {:?} overflows", a, b))
+}
+
+// `(g, x, y)` with `a * x + b * y = g = gcd(a, b)`.
+pub fn extended_gcd<T: SignedInteger>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < T::ZERO {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+// `value` moved into `0..modulus`, `modulus` must be positive.
+pub fn rem_euclid<T: Integer>(value: T, modulus: T) -> T {
+    let rtn = value % modulus;
+    if rtn < T::ZERO {
+        rtn + modulus
+    } else {
+        rtn
+    }
+}
+
+fn add_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+    // Both are below `modulus`, so comparing first avoids overflowing.
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+// Falls back to double and add when `a * b` does not fit into `T`.
+pub fn mul_mod<T: Integer>(a: T, b: T, modulus: T) -> T {
+    let mut a = rem_euclid(a, modulus);
+    let mut b = rem_euclid(b, modulus);
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+
+    let two = T::ONE + T::ONE;
+    let mut rtn = T::ZERO;
+    while b > T::ZERO {
+        if b % two == T::ONE {
+            rtn = add_mod(rtn, a, modulus);
+        }
+        a = add_mod(a, a, modulus);
+        b = b / two;
+    }
+    rtn
+}
+
+pub fn mod_pow<T: Integer>(base: T, mut exponent: T, modulus: T) -> T {
+    let two = T::ONE + T::ONE;
+    let mut base = rem_euclid(base, modulus);
+    let mut rtn = rem_euclid(T::ONE, modulus);
+    while exponent > T::ZERO {
+        if exponent % two == T::ONE {
+            rtn = mul_mod(rtn, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent = exponent / two;
+    }
+    rtn
+}
+
+// None if `a` and `modulus` are not coprime.
+pub fn mod_inverse<T: SignedInteger>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(rem_euclid(a, modulus), modulus);
+    (g == T::ONE).then(|| rem_euclid(x, modulus))
+}
+
+// Smallest non negative `x` with `x = residue (mod modulus)` for every `(residue, modulus)`,
+// together with the lcm of the moduli. The moduli do not have to be coprime, None if the
+// congruences contradict each other or the lcm does not fit into `T`.
+pub fn crt<T: SignedInteger>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences
+        .iter()
+        .try_fold((T::ZERO, T::ONE), |(residue, modulus), &(r, m)| {
+            let r = rem_euclid(r, m);
+            let (g, p, _) = extended_gcd(modulus, m);
+            if (r - residue) % g != T::ZERO {
+                return None;
+            }
+
+            let combined = checked_lcm(modulus, m)?;
+            let step = m / g;
+            let k = mul_mod((r - residue) / g, p, step);
+            // `modulus * k` is below `combined`, so this only overflows if `combined` does.
+            Some((add_mod(residue, modulus * k, combined), combined))
+        })
+}
@@ -0,0 +1,116 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+#[derive(Debug)]
+pub enum ParseError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    InvalidToken {
+        path: PathBuf,
+        // Line and column are 1-based, the column counts bytes.
+        line: usize,
+        column: usize,
+        token: String,
+        expected: &'static str,
+    },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Io { path, source } => {
+                write!(f, "{}: {}", path.display(), source)
+            }
+            ParseError::InvalidToken {
+                path,
+                line,
+                column,
+                token,
+                expected,
+            } => write!(
+                f,
+                "{}:{}:{}: could not parse \"{}\" into {}",
+                path.display(),
+                line,
+                column,
+                token,
+                expected
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseError::Io { source, .. } => Some(source),
+            ParseError::InvalidToken { .. } => None,
+        }
+    }
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+pub fn read_lines(input_file: &str) -> ParseResult<Vec<String>> {
+    let io_error = |source| ParseError::Io {
+        path: PathBuf::from(input_file),
+        source,
+    };
+
+    let file = File::open(input_file).map_err(io_error)?;
+    let reader = BufReader::new(file);
+
+    reader
+        .lines()
+        .collect::<Result<Vec<String>, io::Error>>()
+        .map_err(io_error)
+}
+
+pub fn parse_token<T: FromStr>(
+    input_file: &str,
+    line: usize,
+    column: usize,
+    token: &str,
+    expected: &'static str,
+) -> ParseResult<T> {
+    token.parse::<T>().map_err(|_| ParseError::InvalidToken {
+        path: Path::new(input_file).to_path_buf(),
+        line,
+        column,
+        token: token.to_string(),
+        expected,
+    })
+}
+
+// Splits the line by `separator` and parses every part, keeping track of the column.
+pub fn parse_separated<T: FromStr>(
+    input_file: &str,
+    line_index: usize,
+    line: &str,
+    separator: char,
+    expected: &'static str,
+) -> ParseResult<Vec<T>> {
+    let mut column = 1;
+    let mut rtn = Vec::new();
+
+    for token in line.split(separator) {
+        rtn.push(parse_token(
+            input_file,
+            line_index + 1,
+            column,
+            token,
+            expected,
+        )?);
+        column += token.len() + separator.len_utf8();
+    }
+
+    Ok(rtn)
+}
@@ -0,0 +1,186 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::number::gcd;
+
+// Exact fraction, always reduced and with a positive denominator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self {
+        numerator: 0,
+        denominator: 1,
+    };
+    pub const ONE: Self = Self {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "Rational with a zero denominator");
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Self {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.numerator < 0
+    }
+
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.denominator == 1).then_some(self.numerator)
+    }
+
+    pub fn recip(&self) -> Self {
+        Self::new(self.denominator, self.numerator)
+    }
+
+    // The checked operations return None if an intermediate value does not fit into `i128`.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        // Scaling by the lcm instead of the product keeps the intermediate values small.
+        let divisor = gcd(self.denominator, other.denominator);
+        let numerator = self
+            .numerator
+            .checked_mul(other.denominator / divisor)?
+            .checked_add(other.numerator.checked_mul(self.denominator / divisor)?)?;
+        let denominator = (self.denominator / divisor).checked_mul(other.denominator)?;
+        Some(Self::new(numerator, denominator))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(-other)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        // Cross reducing first, both inputs are already reduced.
+        let a = gcd(self.numerator, other.denominator);
+        let b = gcd(other.numerator, self.denominator);
+        Some(Self::new(
+            (self.numerator / a).checked_mul(other.numerator / b)?,
+            (self.denominator / b).checked_mul(other.denominator / a)?,
+        ))
+    }
+
+    // None on division by zero as well.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        self.checked_mul(other.recip())
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other).expect("Rational overflow")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other).expect("Rational overflow")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.checked_mul(other).expect("Rational overflow")
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        assert!(!other.is_zero(), "Rational division by zero");
+        self.checked_div(other).expect("Rational overflow")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if let (Some(left), Some(right)) = (
+            self.numerator.checked_mul(other.denominator),
+            other.numerator.checked_mul(self.denominator),
+        ) {
+            return left.cmp(&right);
+        }
+
+        // Too large to cross multiply, so compare the integer parts and then the reciprocals of
+        // what is left, like the terms of continued fractions.
+        let (mut a, mut b) = (self.numerator, self.denominator);
+        let (mut c, mut d) = (other.numerator, other.denominator);
+        loop {
+            let rtn = a.div_euclid(b).cmp(&c.div_euclid(d));
+            (a, c) = (a.rem_euclid(b), c.rem_euclid(d));
+            if rtn != Ordering::Equal || a == 0 || c == 0 {
+                return rtn.then(a.cmp(&c));
+            }
+            // a / b < c / d exactly if d / c < b / a.
+            (a, b, c, d) = (d, c, b, a);
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult<S, C> {
+    pub cost: C,
+    // From the start state up to and including the goal state.
+    pub path: Vec<S>,
+    // Number of states that were expanded.
+    pub visited: usize,
+}
+
+struct Node<S, C> {
+    state: S,
+    parent: Option<usize>,
+    cost: C,
+}
+
+fn reconstruct_path<S: Clone, C>(nodes: &[Node<S, C>], index: usize) -> Vec<S> {
+    let mut rtn = Vec::new();
+    let mut current = Some(index);
+    while let Some(index) = current {
+        rtn.push(nodes[index].state.clone());
+        current = nodes[index].parent;
+    }
+    rtn.reverse();
+    rtn
+}
+
+pub fn bfs<S, FN, IN, FG>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: FN,
+    mut is_goal: FG,
+) -> Option<SearchResult<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+    FG: FnMut(&S) -> bool,
+{
+    let mut nodes: Vec<Node<S, usize>> = Vec::new();
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut queue: VecDeque<usize> = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = seen.entry(start.clone()) {
+            entry.insert(nodes.len());
+            queue.push_back(nodes.len());
+            nodes.push(Node {
+                state: start,
+                parent: None,
+                cost: 0,
+            });
+        }
+    }
+
+    let mut visited = 0;
+    while let Some(index) = queue.pop_front() {
+        visited += 1;
+        let state = nodes[index].state.clone();
+        let cost = nodes[index].cost;
+
+        if is_goal(&state) {
+            return Some(SearchResult {
+                cost,
+                path: reconstruct_path(&nodes, index),
+                visited,
+            });
+        }
+
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = seen.entry(next.clone()) {
+                entry.insert(nodes.len());
+                queue.push_back(nodes.len());
+                nodes.push(Node {
+                    state: next,
+                    parent: Some(index),
+                    cost: cost + 1,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+// Distance of every state reachable from the starts, optionally limited to `max_depth` steps.
+pub fn bfs_distances<S, FN, IN>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: FN,
+    max_depth: Option<usize>,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+{
+    let mut distances: HashMap<S, usize> = HashMap::new();
+    let mut queue: VecDeque<(S, usize)> = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = distances.entry(start.clone()) {
+            entry.insert(0);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, distance)) = queue.pop_front() {
+        if max_depth.is_some_and(|max_depth| distance >= max_depth) {
+            continue;
+        }
+
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct QueueItem<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> PartialOrd for QueueItem<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> Ord for QueueItem<C> {
+    // Reversed, so the BinaryHeap pops the lowest estimate first and prefers deeper states on ties.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+pub fn dijkstra<S, C, FN, IN, FG>(
+    starts: impl IntoIterator<Item = S>,
+    successors: FN,
+    is_goal: FG,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FG: FnMut(&S) -> bool,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+// Cheapest cost of every state reachable from the starts.
+pub fn dijkstra_distances<S, C, FN, IN>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: FN,
+) -> HashMap<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+{
+    let mut nodes: Vec<(S, C)> = Vec::new();
+    let mut best: HashMap<S, usize> = HashMap::new();
+    let mut queue: BinaryHeap<QueueItem<C>> = BinaryHeap::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = best.entry(start.clone()) {
+            entry.insert(nodes.len());
+            queue.push(QueueItem {
+                estimate: C::default(),
+                cost: C::default(),
+                index: nodes.len(),
+            });
+            nodes.push((start, C::default()));
+        }
+    }
+
+    while let Some(QueueItem { cost, index, .. }) = queue.pop() {
+        // A cheaper way to this state was found after this item was queued.
+        if cost > nodes[index].1 {
+            continue;
+        }
+
+        let state = nodes[index].0.clone();
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            let next_index = match best.entry(next) {
+                Entry::Vacant(entry) => {
+                    let next_index = nodes.len();
+                    nodes.push((entry.key().clone(), next_cost));
+                    entry.insert(next_index);
+                    next_index
+                }
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if next_cost >= nodes[next_index].1 {
+                        continue;
+                    }
+                    nodes[next_index].1 = next_cost;
+                    next_index
+                }
+            };
+
+            queue.push(QueueItem {
+                estimate: next_cost,
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+
+    nodes.into_iter().collect()
+}
+
+// The heuristic must never overestimate the remaining cost for the result to be optimal.
+pub fn astar<S, C, FN, IN, FH, FG>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    FN: FnMut(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+{
+    let mut nodes: Vec<Node<S, C>> = Vec::new();
+    let mut best: HashMap<S, usize> = HashMap::new();
+    let mut queue: BinaryHeap<QueueItem<C>> = BinaryHeap::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = best.entry(start.clone()) {
+            entry.insert(nodes.len());
+            queue.push(QueueItem {
+                estimate: heuristic(&start),
+                cost: C::default(),
+                index: nodes.len(),
+            });
+            nodes.push(Node {
+                state: start,
+                parent: None,
+                cost: C::default(),
+            });
+        }
+    }
+
+    let mut visited = 0;
+    while let Some(QueueItem { cost, index, .. }) = queue.pop() {
+        // A cheaper way to this state was found after this item was queued.
+        if cost > nodes[index].cost {
+            continue;
+        }
+
+        visited += 1;
+        let state = nodes[index].state.clone();
+
+        if is_goal(&state) {
+            return Some(SearchResult {
+                cost,
+                path: reconstruct_path(&nodes, index),
+                visited,
+            });
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            let next_index = match best.entry(next) {
+                Entry::Vacant(entry) => {
+                    let next_index = nodes.len();
+                    nodes.push(Node {
+                        state: entry.key().clone(),
+                        parent: Some(index),
+                        cost: next_cost,
+                    });
+                    entry.insert(next_index);
+                    next_index
+                }
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if next_cost >= nodes[next_index].cost {
+                        continue;
+                    }
+                    nodes[next_index].parent = Some(index);
+                    nodes[next_index].cost = next_cost;
+                    next_index
+                }
+            };
+
+            queue.push(QueueItem {
+                estimate: next_cost + heuristic(&nodes[next_index].state),
+                cost: next_cost,
+                index: next_index,
+            });
+        }
+    }
+
+    None
+}
@@ -0,0 +1,89 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input_file: &str) -> Self::Input;
+    fn part_1(input: &Self::Input) -> Self::Part1;
+    fn part_2(input: &Self::Input) -> Self::Part2;
+}
+
+// Used by days without a second puzzle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NoSolution;
+
+impl Display for NoSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no solution")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: usize,
+    pub value: String,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub parse_duration: Duration,
+    pub answers: Vec<Answer>,
+}
+
+pub type Solver = fn(&str, Option<usize>) -> Solved;
+
+pub fn solve<S: Solution>(input_file: &str, part: Option<usize>) -> Solved {
+    let start = Instant::now();
+    let input = S::parse(input_file);
+    let parse_duration = start.elapsed();
+
+    let mut answers = Vec::with_capacity(2);
+
+    if part.is_none_or(|p| p == 1) {
+        let start = Instant::now();
+        let value = S::part_1(&input).to_string();
+        answers.push(Answer {
+            part: 1,
+            value,
+            duration: start.elapsed(),
+        });
+    }
+
+    if part.is_none_or(|p| p == 2) {
+        let start = Instant::now();
+        let value = S::part_2(&input).to_string();
+        answers.push(Answer {
+            part: 2,
+            value,
+            duration: start.elapsed(),
+        });
+    }
+
+    Solved {
+        parse_duration,
+        answers,
+    }
+}
+
+// launch_all reads these lines back, keep the wording in sync.
+pub fn run<S: Solution>(input_file: &str) {
+    let solved = solve::<S>(input_file, None);
+    println!(
+        "Parsing took {} microseconds",
+        solved.parse_duration.as_micros()
+    );
+    for answer in solved.answers {
+        println!("Result of part {} is {}", answer.part, answer.value);
+        println!(
+            "Part {} took {} microseconds",
+            answer.part,
+            answer.duration.as_micros()
+        );
+    }
+}